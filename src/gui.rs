@@ -1,14 +1,21 @@
 //! Modern GUI for SCANNR with light/dark/auto theme support
 use crate::input::ScanOrder;
 use crate::port_strategy::PortStrategy;
-use crate::scanner::Scanner;
+use crate::scanner::{
+    BannerGrab, CancelToken, DiscoveryMethod, HostDiscovery, LiveHost, ScanEvent, ScanResult, Scanner,
+};
+use crate::service::{
+    is_tls_port, ProbeProtocol, ServiceDetector, ServiceProbes, TlsInfo, TlsInspector,
+    DEFAULT_EXPIRY_WARNING_DAYS,
+};
 use crate::topology::widget::TopologyWidget;
 use crate::topology::{LayoutType, TopologyStats};
 use egui::{Color32, RichText, Visuals};
+use futures::StreamExt;
 use ipnetwork::IpNetwork;
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
-use std::net::IpAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -127,6 +134,8 @@ pub struct HostInfo {
     pub ports: Vec<u16>,
     pub os: Option<String>,
     pub service_names: HashMap<u16, String>,
+    pub banners: HashMap<u16, String>,
+    pub tls: HashMap<u16, TlsInfo>,
     pub user_alias: Option<String>,
 }
 
@@ -145,6 +154,8 @@ impl HostInfo {
             ports,
             os: None,
             service_names: HashMap::new(),
+            banners: HashMap::new(),
+            tls: HashMap::new(),
             user_alias: None,
         }
     }
@@ -191,14 +202,22 @@ pub struct AppState {
     pub batch_size: u16,
     pub timeout_ms: u32,
     pub udp_scan: bool,
+    pub adaptive_scan: bool,
+    pub grab_banners: bool,
+    pub detect_services: bool,
+    pub inspect_tls: bool,
     pub public_ip: Option<String>,
     pub public_ports: Option<Vec<u16>>,
     pub editing_alias: Option<(IpAddr, String)>,
+    /// Stops the port scan currently running, if any.
+    pub scan_cancel: Option<CancelToken>,
 }
 
 #[derive(Debug, Clone)]
 pub struct NetworkInterface {
     pub name: String,
+    /// Name of the interface in the OS, like eth0.
+    pub device: String,
     pub subnet: String,
     pub gateway: Option<IpAddr>,
     pub priority: u8,
@@ -242,9 +261,14 @@ impl Default for AppState {
             batch_size: 3000,
             timeout_ms: 100,
             udp_scan: false,
+            adaptive_scan: false,
+            grab_banners: false,
+            detect_services: false,
+            inspect_tls: false,
             public_ip: None,
             public_ports: None,
             editing_alias: None,
+            scan_cancel: None,
         }
     }
 }
@@ -268,6 +292,9 @@ const STANDARD_PORTS: [u16; 55] = [
     1433, 1521, 3306, 5432, 6379, 27017,
 ];
 
+// Service detection holds a connection open per port for up to a few seconds
+const SERVICE_DETECTION_CONCURRENCY: usize = 32;
+
 fn detect_device_type(ports: &[u16]) -> String {
     let ports_set: HashSet<u16> = ports.iter().copied().collect();
 
@@ -420,7 +447,7 @@ async fn enumerate_network_interfaces() -> Vec<NetworkInterface> {
     let active_interface_name = detect_default_route_interface();
 
     if let Ok(ifaces) = if_addrs::get_if_addrs() {
-        let mut subnet_map: HashMap<String, (String, Option<IpAddr>)> = HashMap::new();
+        let mut subnet_map: HashMap<String, (String, String, Option<IpAddr>)> = HashMap::new();
 
         for iface in &ifaces {
             let name = &iface.name;
@@ -457,15 +484,16 @@ async fn enumerate_network_interfaces() -> Vec<NetworkInterface> {
 
                 let existing = subnet_map
                     .entry(format!("{}/{}", ip_str, prefix))
-                    .or_insert_with(|| (format!("{} ({})", ip_str, name), Some(gateway)));
+                    .or_insert_with(|| (format!("{} ({})", ip_str, name), name.clone(), Some(gateway)));
 
                 if name.starts_with("eth") || name.starts_with("en") || name.starts_with("wi") {
                     existing.0 = format!("{} ({})", ip_str, name);
+                    existing.1 = name.clone();
                 }
             }
         }
 
-        for (_, (name, gateway)) in subnet_map {
+        for (_, (name, device, gateway)) in subnet_map {
             let name_for_check = name.clone();
             let is_active = active_interface_name
                 .as_ref()
@@ -486,6 +514,7 @@ async fn enumerate_network_interfaces() -> Vec<NetworkInterface> {
 
             interfaces.push(NetworkInterface {
                 name: name.clone(),
+                device,
                 subnet: name,
                 gateway,
                 priority,
@@ -640,6 +669,11 @@ impl eframe::App for ScannrApp {
             state.scan_progress = 0.0;
 
             let subnet = state.subnet_input.clone();
+            // The ARP sweep goes out on the interface picked in the toolbar
+            let device = state
+                .available_interfaces
+                .get(state.selected_interface_idx)
+                .map(|iface| iface.device.clone());
             let cancel_flag = Arc::new(AtomicBool::new(false));
             let state_clone = self.state.clone();
             let ctx_clone = ctx.clone();
@@ -661,6 +695,7 @@ impl eframe::App for ScannrApp {
 
                 let graph = crate::topology::graph::discover_and_build_fast(
                     &subnet,
+                    device.as_deref(),
                     128,
                     150,
                     cancel,
@@ -859,6 +894,9 @@ impl eframe::App for ScannrApp {
                     if state.is_scanning {
                         state.is_scanning = false;
                         state.scan_status = "Scan cancelled".to_string();
+                        if let Some(cancel) = state.scan_cancel.take() {
+                            cancel.cancel();
+                        }
                     } else {
                         state.is_scanning = true;
                         state.scan_status = "Initializing scan...".to_string();
@@ -940,6 +978,14 @@ impl eframe::App for ScannrApp {
                         ui.add_space(20.0);
 
                         ui.checkbox(&mut state.udp_scan, RichText::new("UDP Scan").size(14.0));
+                        ui.checkbox(&mut state.adaptive_scan, RichText::new("Adaptive").size(14.0))
+                            .on_hover_text("Tune the batch size to the network, the slider becomes an upper bound");
+                        ui.checkbox(&mut state.grab_banners, RichText::new("Banners").size(14.0))
+                            .on_hover_text("Read what open services say after connecting, shown under each host");
+                        ui.checkbox(&mut state.detect_services, RichText::new("Services").size(14.0))
+                            .on_hover_text("Identify the software and version behind every open port once the scan is done");
+                        ui.checkbox(&mut state.inspect_tls, RichText::new("TLS").size(14.0))
+                            .on_hover_text("Shake hands with HTTPS and other TLS ports and check their certificates");
                         
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                             ui.label(RichText::new("v1.1.0").weak().size(12.0));
@@ -1237,6 +1283,8 @@ async fn run_passive_scan(
                     ports: ports.clone(),
                     os: None,
                     service_names: HashMap::new(),
+                    banners: HashMap::new(),
+                    tls: HashMap::new(),
                     user_alias: None,
                 };
                 guard.results.push(host);
@@ -1322,17 +1370,23 @@ async fn run_scan(
 
         log::info!("Starting scan: {} IPs x {} ports", total_ips, total_ports);
 
+        let cancel = CancelToken::new();
         {
             let mut state_guard = state.lock().unwrap();
             state_guard.total_ips = total_ips;
+            state_guard.scan_cancel = Some(cancel.clone());
         }
 
-        let (batch_size, timeout, udp_scan) = {
+        let (batch_size, timeout, udp_scan, adaptive_scan, grab_banners, detect_services, inspect_tls) = {
             let state_guard = state.lock().unwrap();
             (
                 state_guard.batch_size,
                 state_guard.timeout_ms,
                 state_guard.udp_scan,
+                state_guard.adaptive_scan,
+                state_guard.grab_banners,
+                state_guard.detect_services,
+                state_guard.inspect_tls,
             )
         };
 
@@ -1385,6 +1439,8 @@ async fn run_scan(
                         ports: Vec::new(),
                         os: Some("Active".to_string()),
                         service_names: HashMap::new(),
+                        banners: HashMap::new(),
+                        tls: HashMap::new(),
                         user_alias: None,
                     };
                     guard.results.push(host);
@@ -1393,113 +1449,40 @@ async fn run_scan(
         }
         ctx.request_repaint();
 
-        // Create callbacks for all scan phases
-        let state_clone = state.clone();
-        let ctx_clone = ctx.clone();
-
-        // Progress should be split between discovery, phase 1 and phase 2
-        let on_progress = std::sync::Arc::new(move |progress: f32| {
-            if let Ok(mut guard) = state_clone.lock() {
-                let status = guard.scan_status.clone();
-                if status.contains("Discovering active hosts") {
-                    guard.scan_progress = progress * 0.1;
-                } else if status.contains("Quick probing") {
-                    guard.scan_progress = 0.1 + (progress * 0.2);
-                } else if status.contains("Scanning remaining") {
-                    guard.scan_progress = 0.3 + (progress * 0.7);
-                }
-            }
-            ctx_clone.request_repaint();
-        });
-
-        let state_result_clone = state.clone();
-        let ctx_result_clone = ctx.clone();
-        let on_result = std::sync::Arc::new(move |socket: std::net::SocketAddr| {
-            if let Ok(mut guard) = state_result_clone.lock() {
-                let ip = socket.ip();
-                let port = socket.port();
-
-                // Find existing host or create new one
-                let mut is_new_port = false;
-                if let Some(host) = guard.results.iter_mut().find(|h| h.ip == ip) {
-                    if !host.ports.contains(&port) {
-                        host.ports.push(port);
-                        host.ports.sort_unstable();
-                        host.os = Some(detect_device_type(&host.ports));
-                        is_new_port = true;
-                    }
-                } else {
-                    let mut host = HostInfo {
-                        ip,
-                        hostname: None,
-                        mac: None,
-                        vendor: None,
-                        ports: vec![port],
-                        os: None,
-                        service_names: HashMap::new(),
-                        user_alias: None,
-                    };
-                    host.os = Some(detect_device_type(&host.ports));
-                    guard.results.push(host);
-                    is_new_port = true;
-                }
-
-                if is_new_port {
-                    guard.scanned_ports += 1;
-                }
-            }
-            ctx_result_clone.request_repaint();
-        });
-
-        // Phase 0.5: Active Host Discovery (TCP Ping Sweep)
-        // We probe a common port on EVERY IP to ensure the OS populates the ARP table
-        // and we find hosts not in the cache.
+        // Phase 0.5: Active Host Discovery
+        // ARP for the local subnet, ICMP and TCP pings for everything else.
+        // Without raw sockets only the TCP pings are left, as connects.
         {
             let mut guard = state.lock().unwrap();
             guard.scan_status = format!("Discovering active hosts in {}...", subnet);
         }
         ctx.request_repaint();
 
-        // Use a tiny timeout for discovery
-        let discovery_timeout = Duration::from_millis(50);
-        let discovery_strategy =
-            PortStrategy::pick(&None, Some(vec![80, 443, 445, 22]), ScanOrder::Serial);
-        let discovery_scanner = Scanner::new(
-            &ips,
-            batch_size.max(5000), // High concurrency for discovery
-            discovery_timeout,
-            1,
-            true,
-            discovery_strategy,
-            false,
-            vec![],
-            false,
-        );
-
-        // Result callback for discovery: just to trigger ARP lookups later or find hosts
-        let state_discovery_clone = state.clone();
-        let on_discovery_result = std::sync::Arc::new(move |socket: std::net::SocketAddr| {
-            if let Ok(mut guard) = state_discovery_clone.lock() {
-                let ip = socket.ip();
-                if !guard.results.iter().any(|h| h.ip == ip) {
-                    let host = HostInfo {
-                        ip,
-                        hostname: None,
-                        mac: None,
-                        vendor: None,
-                        ports: vec![socket.port()],
-                        os: Some("Active".to_string()),
-                        service_names: HashMap::new(),
-                        user_alias: None,
-                    };
-                    guard.results.push(host);
-                }
+        let discovery = HostDiscovery::new(vec![
+            DiscoveryMethod::Arp,
+            DiscoveryMethod::IcmpEcho,
+            DiscoveryMethod::TcpSyn(vec![22, 80, 443, 445]),
+            DiscoveryMethod::TcpAck(vec![80]),
+            DiscoveryMethod::IcmpTimestamp,
+        ])
+        .with_timeout(timeout)
+        .with_concurrency(usize::from(batch_size))
+        .with_cancel_token(cancel.clone());
+        let report = discovery.run(&ips).await;
+        if cancel.is_cancelled() {
+            return;
+        }
+        for (method, reason) in &report.skipped {
+            log::warn!("Host discovery: skipping {}: {}", method, reason);
+        }
+        {
+            let mut guard = state.lock().unwrap();
+            for live in &report.live {
+                record_live_host(&mut guard, live);
             }
-        });
-
-        discovery_scanner
-            .run(Some(on_progress.clone()), Some(on_discovery_result))
-            .await;
+            guard.scan_progress = 0.1;
+        }
+        ctx.request_repaint();
 
         // Refresh ARP after discovery probe
         let arp_entries = crate::topology::discovery::get_arp_entries().await;
@@ -1516,21 +1499,43 @@ async fn run_scan(
             }
         }
         ctx.request_repaint();
+
+        // Neighbours from the ARP cache count as up even if they ignored
+        // every probe.
+        let ips: Vec<IpAddr> = {
+            let guard = state.lock().unwrap();
+            ips.iter()
+                .copied()
+                .filter(|ip| guard.results.iter().any(|h| h.ip == *ip))
+                .collect()
+        };
+        let total_ips = ips.len();
+        if total_ips == 0 {
+            let mut state_guard = state.lock().unwrap();
+            state_guard.is_scanning = false;
+            state_guard.scan_cancel = None;
+            state_guard.scan_status = format!("No active hosts found in {}.", subnet);
+            state_guard.scan_progress = 1.0;
+            drop(state_guard);
+            ctx.request_repaint();
+            return;
+        }
+
         // Phase 1: Quick Scan (Common ports)
         let quick_ports = QUICK_PORTS.to_vec();
         let quick_strategy =
             PortStrategy::pick(&None, Some(quick_ports.clone()), ScanOrder::Serial);
-        let quick_scanner = Scanner::new(
-            &ips,
-            batch_size,
-            timeout,
-            1,
-            true,
-            quick_strategy,
-            false,
-            vec![],
-            udp_scan,
-        );
+        let mut quick_scanner = Scanner::builder(&ips)
+            .with_batch_size(batch_size)
+            .with_timeout(timeout)
+            .with_port_strategy(quick_strategy)
+            .with_udp(udp_scan)
+            .with_cancel_token(cancel.clone())
+            .with_adaptive(adaptive_scan);
+        if grab_banners {
+            quick_scanner = quick_scanner.with_banner_grab(BannerGrab::default());
+        }
+        let quick_scanner = quick_scanner.build();
 
         {
             let mut guard = state.lock().unwrap();
@@ -1538,9 +1543,10 @@ async fn run_scan(
         }
         ctx.request_repaint();
 
-        quick_scanner
-            .run(Some(on_progress.clone()), Some(on_result.clone()))
-            .await;
+        drive_scan(quick_scanner, &state, &ctx, 0.1, 0.2, record_open_port).await;
+        if cancel.is_cancelled() {
+            return;
+        }
 
         // Phase 2: Main Scan (Remaining ports)
         let remaining_ports: Vec<u16> = ports
@@ -1557,23 +1563,56 @@ async fn run_scan(
             ctx.request_repaint();
 
             let main_strategy = PortStrategy::pick(&None, Some(remaining_ports), ScanOrder::Serial);
-            let main_scanner = Scanner::new(
-                &ips,
-                batch_size,
-                timeout,
-                1,
-                true,
-                main_strategy,
-                false,
-                vec![],
-                udp_scan,
-            );
-            main_scanner.run(Some(on_progress), Some(on_result)).await;
+            let mut main_scanner = Scanner::builder(&ips)
+                .with_batch_size(batch_size)
+                .with_timeout(timeout)
+                .with_port_strategy(main_strategy)
+                .with_udp(udp_scan)
+                .with_cancel_token(cancel.clone())
+                .with_adaptive(adaptive_scan);
+            if grab_banners {
+                main_scanner = main_scanner.with_banner_grab(BannerGrab::default());
+            }
+            let main_scanner = main_scanner.build();
+            drive_scan(main_scanner, &state, &ctx, 0.3, 0.7, record_open_port).await;
+            if cancel.is_cancelled() {
+                return;
+            }
+        }
+
+        // Phase 3: Service detection on everything found open
+        if detect_services {
+            {
+                let mut guard = state.lock().unwrap();
+                guard.scan_status = "Identifying services on open ports...".to_string();
+            }
+            ctx.request_repaint();
+
+            let protocol = if udp_scan { ProbeProtocol::Udp } else { ProbeProtocol::Tcp };
+            identify_services(&state, &ctx, &cancel, timeout, protocol).await;
+            if cancel.is_cancelled() {
+                return;
+            }
+        }
+
+        // Phase 4: TLS handshakes with the ports that speak it
+        if inspect_tls && !udp_scan {
+            {
+                let mut guard = state.lock().unwrap();
+                guard.scan_status = "Inspecting TLS certificates...".to_string();
+            }
+            ctx.request_repaint();
+
+            inspect_tls_ports(&state, &ctx, &cancel, timeout).await;
+            if cancel.is_cancelled() {
+                return;
+            }
         }
 
         // Final update
         let mut state_guard = state.lock().unwrap();
         state_guard.is_scanning = false;
+        state_guard.scan_cancel = None;
         state_guard.scan_status = format!(
             "Scan complete! Found {} hosts with {} total open ports",
             state_guard.results.len(),
@@ -1594,6 +1633,184 @@ async fn run_scan(
     }
 }
 
+/// Runs a scanner and feeds its events into the app state.
+/// The scanner's own progress is mapped onto `phase_start..phase_start + phase_span`
+/// of the overall progress bar, so every phase owns a fixed slice of it.
+async fn drive_scan(
+    scanner: Scanner,
+    state: &Arc<Mutex<AppState>>,
+    ctx: &egui::Context,
+    phase_start: f32,
+    phase_span: f32,
+    on_open: fn(&mut AppState, &ScanResult),
+) {
+    let mut events = std::pin::pin!(scanner.events());
+    while let Some(event) = events.next().await {
+        match event {
+            ScanEvent::Progress(progress) => {
+                if let Ok(mut guard) = state.lock() {
+                    guard.scan_progress = phase_start + progress.fraction() * phase_span;
+                }
+            }
+            ScanEvent::PortResult(result) if result.state.is_open() => {
+                if let Ok(mut guard) = state.lock() {
+                    on_open(&mut guard, &result);
+                }
+            }
+            _ => continue,
+        }
+        ctx.request_repaint();
+    }
+}
+
+/// Discovery only tells us a host is alive, and its MAC when ARP found it.
+fn record_live_host(state: &mut AppState, live: &LiveHost) {
+    if let Some(host) = state.results.iter_mut().find(|h| h.ip == live.ip) {
+        if host.mac.is_none() {
+            host.vendor = live.mac.as_deref().and_then(crate::oui::lookup_vendor);
+            host.mac.clone_from(&live.mac);
+        }
+        return;
+    }
+    let host = HostInfo {
+        ip: live.ip,
+        hostname: None,
+        mac: live.mac.clone(),
+        vendor: live.mac.as_deref().and_then(crate::oui::lookup_vendor),
+        ports: Vec::new(),
+        os: Some("Active".to_string()),
+        service_names: HashMap::new(),
+        banners: HashMap::new(),
+        tls: HashMap::new(),
+        user_alias: None,
+    };
+    state.results.push(host);
+}
+
+fn record_open_port(state: &mut AppState, result: &ScanResult) {
+    let ip = result.socket.ip();
+    let port = result.socket.port();
+
+    // Find existing host or create new one
+    let mut is_new_port = false;
+    if let Some(host) = state.results.iter_mut().find(|h| h.ip == ip) {
+        if !host.ports.contains(&port) {
+            host.ports.push(port);
+            host.ports.sort_unstable();
+            host.os = Some(detect_device_type(&host.ports));
+            is_new_port = true;
+        }
+    } else {
+        let mut host = HostInfo {
+            ip,
+            hostname: None,
+            mac: None,
+            vendor: None,
+            ports: vec![port],
+            os: None,
+            service_names: HashMap::new(),
+            banners: HashMap::new(),
+            tls: HashMap::new(),
+            user_alias: None,
+        };
+        host.os = Some(detect_device_type(&host.ports));
+        state.results.push(host);
+        is_new_port = true;
+    }
+
+    if let Some(banner) = &result.banner {
+        if let Some(host) = state.results.iter_mut().find(|h| h.ip == ip) {
+            host.banners.insert(port, banner.to_string());
+            if let Some(service) = ServiceProbes::embedded().match_banner(banner) {
+                host.service_names.insert(port, service.to_string());
+            }
+        }
+    }
+
+    if is_new_port {
+        state.scanned_ports += 1;
+    }
+}
+
+/// Runs service detection on every open port found so far, a few at a time.
+async fn identify_services(
+    state: &Arc<Mutex<AppState>>,
+    ctx: &egui::Context,
+    cancel: &CancelToken,
+    timeout: Duration,
+    protocol: ProbeProtocol,
+) {
+    let sockets: Vec<SocketAddr> = {
+        let guard = state.lock().unwrap();
+        guard
+            .results
+            .iter()
+            .flat_map(|host| host.ports.iter().map(move |port| SocketAddr::new(host.ip, *port)))
+            .collect()
+    };
+
+    let detector = ServiceDetector::new(ServiceProbes::embedded()).with_timeout(timeout);
+    let detector = &detector;
+    let mut detections = futures::stream::iter(sockets)
+        .map(|socket| async move { (socket, detector.detect(socket, protocol, None).await) })
+        .buffer_unordered(SERVICE_DETECTION_CONCURRENCY);
+    while let Some((socket, service)) = detections.next().await {
+        if cancel.is_cancelled() {
+            return;
+        }
+        let Some(service) = service else { continue };
+        if let Ok(mut guard) = state.lock() {
+            if let Some(host) = guard.results.iter_mut().find(|h| h.ip == socket.ip()) {
+                host.service_names.insert(socket.port(), service.to_string());
+            }
+        }
+        ctx.request_repaint();
+    }
+}
+
+/// Shakes hands with every open TLS port found so far.
+async fn inspect_tls_ports(
+    state: &Arc<Mutex<AppState>>,
+    ctx: &egui::Context,
+    cancel: &CancelToken,
+    timeout: Duration,
+) {
+    let targets: Vec<(SocketAddr, Option<String>)> = {
+        let guard = state.lock().unwrap();
+        guard
+            .results
+            .iter()
+            .flat_map(|host| {
+                host.ports
+                    .iter()
+                    .filter(|port| is_tls_port(**port))
+                    .map(move |port| (SocketAddr::new(host.ip, *port), host.hostname.clone()))
+            })
+            .collect()
+    };
+
+    // The connect is followed by at least two more round trips.
+    let inspector = TlsInspector::new().with_timeout(timeout * 3);
+    let inspector = &inspector;
+    let mut handshakes = futures::stream::iter(targets)
+        .map(|(socket, hostname)| async move {
+            (socket, inspector.inspect(socket, hostname.as_deref()).await)
+        })
+        .buffer_unordered(SERVICE_DETECTION_CONCURRENCY);
+    while let Some((socket, info)) = handshakes.next().await {
+        if cancel.is_cancelled() {
+            return;
+        }
+        let Ok(info) = info else { continue };
+        if let Ok(mut guard) = state.lock() {
+            if let Some(host) = guard.results.iter_mut().find(|h| h.ip == socket.ip()) {
+                host.tls.insert(socket.port(), info);
+            }
+        }
+        ctx.request_repaint();
+    }
+}
+
 fn spawn_ssh_terminal(
     ip: IpAddr,
     port: u16,
@@ -1962,6 +2179,27 @@ fn draw_list_view(ui: &mut egui::Ui, state: &mut AppState) {
                                 .join(", ")
                         );
                         ui.label(RichText::new(ports_text).size(18.0).color(Color32::from_rgb(100, 200, 100)));
+
+                        for port in &host.ports {
+                            let detail = host.service_names.get(port).or_else(|| host.banners.get(port));
+                            if let Some(detail) = detail {
+                                ui.label(RichText::new(format!("{}: {}", port, detail)).size(12.0).monospace().weak());
+                            }
+                            if let Some(tls) = host.tls.get(port) {
+                                let now = chrono::Utc::now();
+                                let mut text = format!("{}: {} {}", port, tls.version, tls.cipher_suite);
+                                if let Some(leaf) = tls.leaf() {
+                                    text = format!("{}, {} {}", text, leaf.subject, leaf.expiry_note(now));
+                                }
+                                let expiring = !tls.expiring(now, DEFAULT_EXPIRY_WARNING_DAYS).is_empty();
+                                let color = if expiring {
+                                    Color32::from_rgb(230, 80, 80)
+                                } else {
+                                    Color32::GRAY
+                                };
+                                ui.label(RichText::new(text).size(12.0).monospace().color(color));
+                            }
+                        }
                     });
                     ui.add_space(5.0);
                 }
@@ -2294,8 +2532,13 @@ fn draw_topology_view(
                         run_scan(&subnet, state_clone, ctx_clone, scan_mode).await;
                     });
                 }
-                crate::topology::TopologyAction::OpenWeb(ip, _port) => {
-                    open_in_browser(&format!("http://{}", ip));
+                crate::topology::TopologyAction::OpenWeb(ip, port) => {
+                    let scheme = crate::service::web_scheme(port).unwrap_or(crate::service::Scheme::Http);
+                    let host = match ip {
+                        IpAddr::V4(ip) => ip.to_string(),
+                        IpAddr::V6(ip) => format!("[{}]", ip),
+                    };
+                    open_in_browser(&format!("{}://{}:{}", scheme, host, port));
                 }
                 _ => {}
             }
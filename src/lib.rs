@@ -6,8 +6,12 @@
 //! ## Example: perform a scan against localhost
 //!
 //! The core scanning behaviour is managed by
-//! [`Scanner`](crate::scanner::Scanner) which in turn requires a
-//! [`PortStrategy`](crate::port_strategy::PortStrategy):
+//! [`Scanner`](crate::scanner::Scanner), set up with a
+//! [`ScannerBuilder`](crate::scanner::ScannerBuilder) and usually a
+//! [`PortStrategy`](crate::port_strategy::PortStrategy). The library never
+//! prints, what a scan found comes back as a
+//! [`ScanReport`](crate::scanner::ScanReport) or as it happens through
+//! [`Scanner::run_with`](crate::scanner::Scanner::run_with):
 //!
 //! ```rust
 //! use async_std::task::block_on;
@@ -15,7 +19,7 @@
 //!
 //! use SCANNR::input::{PortRange, ScanOrder};
 //! use SCANNR::port_strategy::PortStrategy;
-//! use SCANNR::scanner::Scanner;
+//! use SCANNR::scanner::{ScanEvent, Scanner};
 //!
 //! fn main() {
 //!     let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
@@ -24,21 +28,20 @@
 //!         end: 1_000,
 //!     };
 //!     let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
-//!     let scanner = Scanner::new(
-//!         &addrs,
-//!         10,
-//!         Duration::from_millis(100),
-//!         1,
-//!         true,
-//!         strategy,
-//!         true,
-//!         vec![9000],
-//!         false,
-//!     );
+//!     let scanner = Scanner::builder(&addrs)
+//!         .with_batch_size(10)
+//!         .with_timeout(Duration::from_millis(100))
+//!         .with_port_strategy(strategy)
+//!         .with_exclude_ports(vec![9000])
+//!         .build();
 //!
-//!     let scan_result = block_on(scanner.run(None, None));
+//!     let scan_report = block_on(scanner.run_with(|event| {
+//!         if let ScanEvent::PortResult(result) = event {
+//!             println!("{} is {}", result.socket, result.state);
+//!         }
+//!     }));
 //!
-//!     println!("{:?}", scan_result);
+//!     println!("{:?}", scan_report.summary);
 //! }
 //! ```
 #![allow(clippy::needless_doctest_main)]
@@ -49,6 +52,8 @@ pub mod input;
 
 pub mod scanner;
 
+pub mod service;
+
 pub mod port_strategy;
 
 pub mod benchmark;
@@ -58,9 +63,6 @@ pub mod scripts;
 #[allow(non_snake_case)]
 pub mod address;
 
-#[rustfmt::skip]
-pub mod generated;
-
 pub mod gui;
 
 #[rustfmt::skip]
@@ -3,17 +3,27 @@
 #![allow(clippy::doc_markdown, clippy::if_not_else, clippy::non_ascii_literal)]
 
 use SCANNR::benchmark::{Benchmark, NamedTimer};
-use SCANNR::input::{self, Config, Opts, ScriptsRequired};
+use SCANNR::input::{self, Config, Opts, ScanOrder, ScriptsRequired};
 use SCANNR::port_strategy::PortStrategy;
-use SCANNR::scanner::Scanner;
+#[cfg(unix)]
+use SCANNR::scanner::SynScanner;
+use SCANNR::scanner::{
+    BannerGrab, CancelToken, Checkpoint, HostDiscovery, ProxyTransport, ScanEvent, ScanReport,
+    Scanner, Source, StopReason, UdpPayloads,
+};
 use SCANNR::scripts::{init_scripts, Script, ScriptFile};
+use SCANNR::service::{
+    is_tls_port, ProbeProtocol, ServiceDetector, ServiceMatch, ServiceProbes, TlsInfo, TlsInspector,
+};
 use SCANNR::{detail, funny_opening, output, warning};
 
 use colorful::{Color, Colorful};
 use futures::executor::block_on;
+use futures::StreamExt;
 use std::collections::HashMap;
-use std::net::IpAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::string::ToString;
+use std::sync::Arc;
 use std::time::Duration;
 
 use SCANNR::address::parse_addresses;
@@ -26,6 +36,10 @@ extern crate dirs;
 const DEFAULT_FILE_DESCRIPTORS_LIMIT: u64 = 8000;
 // Safest batch size based on experimentation
 const AVERAGE_BATCH_SIZE: u16 = 3000;
+// Each detection may hold a connection open for several seconds.
+const SERVICE_DETECTION_CONCURRENCY: usize = 64;
+// Proxies tend to choke well before the scanner does.
+const DEFAULT_PROXY_CONCURRENCY: usize = 32;
 
 #[macro_use]
 extern crate log;
@@ -66,7 +80,66 @@ fn main() {
         print_opening(&opts);
     }
 
-    let ips: Vec<IpAddr> = parse_addresses(&opts);
+    let resume = opts
+        .resume
+        .as_ref()
+        .map(|path| match Checkpoint::load(path) {
+            Ok(checkpoint) => checkpoint,
+            Err(e) => {
+                warning!(
+                    format!("Cannot resume the scan: {e:#}"),
+                    opts.greppable,
+                    opts.accessible
+                );
+                std::process::exit(1);
+            }
+        });
+    if let Some(checkpoint) = &resume {
+        opts.udp = checkpoint.udp;
+    }
+
+    if opts.shard.is_some() && opts.seed.is_none() && opts.scan_order != ScanOrder::Serial {
+        warning!(
+            "A sharded scan in random order needs --seed, so that every worker picks the same order.",
+            opts.greppable,
+            opts.accessible
+        );
+        std::process::exit(1);
+    }
+
+    if opts.proxy.is_some() {
+        if opts.udp {
+            warning!(
+                "UDP cannot be scanned through a proxy, only TCP.",
+                opts.greppable,
+                opts.accessible
+            );
+            std::process::exit(1);
+        }
+        // These would connect to the targets directly, past the proxy.
+        if opts.syn {
+            warning!(
+                "SYN probes cannot go through a proxy, falling back to connect scan.",
+                opts.greppable,
+                opts.accessible
+            );
+            opts.syn = false;
+        }
+        if opts.service_detection || opts.tls {
+            warning!(
+                "Service detection and TLS inspection do not go through the proxy, skipping them.",
+                opts.greppable,
+                opts.accessible
+            );
+            opts.service_detection = false;
+            opts.tls = false;
+        }
+    }
+
+    let ips: Vec<IpAddr> = match &resume {
+        Some(checkpoint) => checkpoint.ips.clone(),
+        None => parse_addresses(&opts),
+    };
 
     if ips.is_empty() {
         warning!(
@@ -77,33 +150,279 @@ fn main() {
         std::process::exit(1);
     }
 
+    let source = match source_of(&opts) {
+        Ok(source) => source,
+        Err(e) => {
+            warning!(
+                format!("Cannot send from the given source: {e}"),
+                opts.greppable,
+                opts.accessible
+            );
+            std::process::exit(1);
+        }
+    };
+
     #[cfg(unix)]
-    let batch_size: u16 = infer_batch_size(&opts, adjust_ulimit_size(&opts));
+    let syn = if opts.syn && !opts.udp {
+        match SynScanner::from_source(&source) {
+            Ok(syn) => Some(syn),
+            Err(e) => {
+                let reason = if e.kind() == std::io::ErrorKind::PermissionDenied {
+                    format!("SYN scan needs root or CAP_NET_RAW ({e})")
+                } else {
+                    format!("SYN scan is not possible: {e}")
+                };
+                warning!(
+                    format!("{reason}, falling back to connect scan."),
+                    opts.greppable,
+                    opts.accessible
+                );
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // A SYN scan keeps no socket open per probe, so the file limit does not
+    // bound the batch size.
+    #[cfg(unix)]
+    let batch_size: u16 = if syn.is_some() {
+        opts.batch_size
+    } else {
+        infer_batch_size(&opts, adjust_ulimit_size(&opts))
+    };
 
     #[cfg(not(unix))]
     let batch_size: u16 = AVERAGE_BATCH_SIZE;
 
-    let scanner = Scanner::new(
-        &ips,
-        batch_size,
-        Duration::from_millis(opts.timeout.into()),
-        opts.tries,
-        opts.greppable,
-        PortStrategy::pick(&opts.range, opts.ports, opts.scan_order),
-        opts.accessible,
-        opts.exclude_ports.unwrap_or_default(),
-        opts.udp,
-    );
+    let cancel = CancelToken::new();
+    install_interrupt_handler(cancel.clone());
+
+    // The hosts of a resumed scan went through discovery the first time.
+    // Shards need the same hosts everywhere to split the sockets evenly.
+    // Discovery probes cannot go through a proxy.
+    let discovered =
+        !(opts.skip_discovery || resume.is_some() || opts.shard.is_some() || opts.proxy.is_some());
+    let ips = if discovered {
+        discover_hosts(&ips, &opts, batch_size, &cancel, &source)
+    } else {
+        ips
+    };
+
+    if ips.is_empty() {
+        warning!(
+            "No host answered the discovery probes, aborting scan. Use --skip-discovery to scan hosts that ignore them.",
+            opts.greppable,
+            opts.accessible
+        );
+        std::process::exit(1);
+    }
+
+    let seed = opts.seed.unwrap_or_else(rand::random);
+    let mut scanner = Scanner::builder(&ips)
+        .with_batch_size(batch_size)
+        .with_timeout(Duration::from_millis(opts.timeout.into()))
+        .with_tries(opts.tries)
+        .with_port_strategy(PortStrategy::pick_with_seed(
+            &opts.range,
+            opts.ports.clone(),
+            opts.scan_order,
+            seed,
+        ))
+        .with_exclude_ports(opts.exclude_ports.clone().unwrap_or_default())
+        .with_udp(opts.udp)
+        .with_cancel_token(cancel)
+        .with_adaptive(opts.adaptive)
+        .with_source(source.clone());
+    if let Some(seconds) = opts.max_scan_time {
+        scanner = scanner.with_deadline(Duration::from_secs(seconds));
+    }
+    if let Some(seconds) = opts.host_timeout {
+        scanner = scanner.with_host_deadline(Duration::from_secs(seconds));
+    }
+    if let Some(probes) = opts.dead_host_threshold {
+        scanner = scanner.with_dead_host_threshold(probes);
+        // Whatever answered discovery is up, even if every port is filtered.
+        if discovered {
+            scanner = scanner.with_live_hosts(&ips);
+        }
+    }
+    if opts.dynamic_timeout {
+        scanner = scanner.with_dynamic_timeout(
+            Duration::from_millis(opts.min_timeout.into()),
+            Duration::from_millis(opts.max_timeout.into()),
+        );
+    }
+    if let Some(rate) = opts.max_rate {
+        scanner = scanner.with_max_rate(rate);
+    }
+    if let Some(limit) = opts.max_per_host {
+        scanner = scanner.with_max_per_host(limit);
+    }
+    if let Some(limit) = opts.max_per_subnet {
+        scanner = scanner.with_max_per_subnet(limit);
+    }
+    if opts.grab_banners {
+        scanner = scanner.with_banner_grab(BannerGrab::default());
+    }
+    if let Some(path) = opts.udp_payloads.as_ref().filter(|_| opts.udp) {
+        match UdpPayloads::from_file(path) {
+            Ok(payloads) => {
+                scanner = scanner.with_udp_payloads(UdpPayloads::default().merge(&payloads));
+            }
+            Err(e) => warning!(
+                format!(
+                    "Could not load UDP payloads from {}: {e}. Using the built-in ones.",
+                    path.display()
+                ),
+                opts.greppable,
+                opts.accessible
+            ),
+        }
+    }
+    #[cfg(unix)]
+    if let Some(syn) = syn {
+        scanner = scanner.with_syn(syn);
+    }
+    if let Some(path) = opts.checkpoint.clone().or_else(|| opts.resume.clone()) {
+        scanner = scanner.with_checkpoint(path);
+    }
+    if let Some(shard) = opts.shard {
+        scanner = scanner.with_shard(shard);
+    }
+    if opts.scan_order == ScanOrder::RandomSockets {
+        scanner = scanner.with_shuffled_sockets(seed);
+    }
+    if let Some(checkpoint) = resume {
+        scanner = scanner.with_resume(checkpoint);
+    }
     debug!("Scanner finished building: {scanner:?}");
 
     let mut portscan_bench = NamedTimer::start("Portscan");
-    let scan_result = block_on(scanner.run(None, None));
+    let print_open = |event: ScanEvent| {
+        if let ScanEvent::PortResult(result) = event {
+            if result.state.is_open() {
+                print_open_port(result.socket, &opts);
+            }
+        }
+    };
+    let scan_report = match opts.proxy.clone() {
+        Some(proxy) => {
+            let max_in_flight = opts.proxy_concurrency.unwrap_or(DEFAULT_PROXY_CONCURRENCY);
+            let transport = match ProxyTransport::new(proxy, source.clone(), max_in_flight) {
+                Ok(transport) => transport,
+                Err(e) => {
+                    warning!(
+                        format!("Cannot use the proxy: {e}"),
+                        opts.greppable,
+                        opts.accessible
+                    );
+                    std::process::exit(1);
+                }
+            };
+            block_on(
+                scanner
+                    .with_transport(transport)
+                    .build()
+                    .run_with(print_open),
+            )
+        }
+        None => block_on(scanner.build().run_with(print_open)),
+    };
     portscan_bench.end();
     benchmarks.push(portscan_bench);
 
+    let mut skipped_hosts: Vec<_> = scan_report.skipped_hosts.iter().collect();
+    skipped_hosts.sort_unstable_by_key(|(ip, _)| **ip);
+    for (ip, reason) in skipped_hosts {
+        warning!(
+            format!("Skipped the rest of {ip}, it {reason}."),
+            opts.greppable,
+            opts.accessible
+        );
+    }
+
+    if !scan_report.is_complete() {
+        let reason = match scan_report.summary.stopped {
+            Some(StopReason::Cancelled) => "Scan interrupted",
+            Some(StopReason::DeadlineExceeded) => "Maximum scan time reached",
+            None => "Some hosts were skipped",
+        };
+        warning!(
+            format!(
+                "{reason}, {} sockets were left unscanned. The results below are partial.",
+                scan_report.summary.unscanned
+            ),
+            opts.greppable,
+            opts.accessible
+        );
+    }
+
+    if let Some(shard) = scan_report.shard {
+        detail!(
+            format!("Scanned shard {shard} of the sockets"),
+            opts.greppable,
+            opts.accessible
+        );
+    }
+
+    for result in &scan_report.results {
+        if let Some(banner) = &result.banner {
+            detail!(
+                format!("{} {}", result.socket, banner),
+                opts.greppable,
+                opts.accessible
+            );
+        }
+    }
+
+    if opts.service_detection {
+        for (socket, service) in detect_services(&scan_report, &opts, &source) {
+            detail!(
+                format!("{socket} {service}"),
+                opts.greppable,
+                opts.accessible
+            );
+        }
+    }
+
+    if opts.tls && !opts.udp {
+        let now = chrono::Utc::now();
+        for (socket, info) in inspect_tls(&scan_report, &opts, &source) {
+            detail!(format!("{socket} {info}"), opts.greppable, opts.accessible);
+            for certificate in &info.certificates {
+                detail!(
+                    format!(
+                        "{socket} certificate {} issued by {}, {}, valid {} to {}, SANs: {}",
+                        certificate.subject,
+                        certificate.issuer,
+                        certificate.key_type,
+                        certificate.not_before.format("%Y-%m-%d"),
+                        certificate.not_after.format("%Y-%m-%d"),
+                        certificate.sans.join(", ")
+                    ),
+                    opts.greppable,
+                    opts.accessible
+                );
+            }
+            for certificate in info.expiring(now, i64::from(opts.cert_expiry_days)) {
+                warning!(
+                    format!(
+                        "{socket} certificate {} {}",
+                        certificate.subject,
+                        certificate.expiry_note(now)
+                    ),
+                    opts.greppable,
+                    opts.accessible
+                );
+            }
+        }
+    }
+
     let mut ports_per_ip = HashMap::new();
 
-    for socket in scan_result {
+    for socket in scan_report.open_sockets() {
         ports_per_ip
             .entry(socket.ip())
             .or_insert_with(Vec::new)
@@ -118,9 +437,14 @@ fn main() {
         // If we got here it means the IP was not found within the HashMap, this
         // means the scan couldn't find any open ports for it.
 
+        let adaptive_hint = if opts.adaptive {
+            ""
+        } else {
+            "\n Or let the scanner tune the batch size to your network with --adaptive.\n"
+        };
         let x = format!("Looks like I didn't find any open ports for {ip:?}. This is usually caused by a high batch size.
         \n*I used {} batch size, consider lowering it with {} or a comfortable number for your system.
-        \n Alternatively, increase the timeout if your ping is high. Rustscan -t 2000 for 2000 milliseconds (2s) timeout.\n",
+        \n Alternatively, increase the timeout if your ping is high. Rustscan -t 2000 for 2000 milliseconds (2s) timeout.\n{adaptive_hint}",
         opts.batch_size,
         "'rustscan -b <batch_size> -a <ip address>'");
         warning!(x, opts.greppable, opts.accessible);
@@ -190,6 +514,166 @@ fn main() {
     info!("{}", benchmarks.summary());
 }
 
+/// Cancels the scan on the first Ctrl-C so the partial results still get
+/// printed, and exits right away on the second one.
+fn install_interrupt_handler(cancel: CancelToken) {
+    let result = ctrlc::set_handler(move || {
+        if cancel.is_cancelled() {
+            std::process::exit(130);
+        }
+        cancel.cancel();
+    });
+
+    if let Err(e) = result {
+        debug!("Could not install the Ctrl-C handler: {e}");
+    }
+}
+
+/// Narrows `ips` down to the hosts that answer one of the discovery probes.
+fn discover_hosts(
+    ips: &[IpAddr],
+    opts: &Opts,
+    concurrency: u16,
+    cancel: &CancelToken,
+    source: &Source,
+) -> Vec<IpAddr> {
+    let discovery = HostDiscovery::new(opts.ping.clone())
+        .with_timeout(Duration::from_millis(opts.timeout.into()))
+        .with_tries(opts.tries)
+        .with_concurrency(concurrency.into())
+        .with_cancel_token(cancel.clone())
+        .with_source(source.clone());
+    let report = block_on(discovery.run(ips));
+
+    for (method, reason) in &report.skipped {
+        warning!(
+            format!("Host discovery: {method} needs root or CAP_NET_RAW ({reason}), skipping it."),
+            opts.greppable,
+            opts.accessible
+        );
+    }
+    for host in &report.live {
+        debug!("{} is up: {}", host.ip, host.reason);
+    }
+    detail!(
+        format!("{} of {} hosts are up.", report.live.len(), ips.len()),
+        opts.greppable,
+        opts.accessible
+    );
+    report.live_ips()
+}
+
+/// Prints an open port as soon as the scan finds it, unless the output is
+/// meant for grep.
+fn print_open_port(socket: SocketAddr, opts: &Opts) {
+    if opts.greppable {
+        return;
+    }
+    if opts.accessible {
+        println!("Open {socket}");
+    } else {
+        println!("Open {}", socket.to_string().color(Color::Magenta));
+    }
+}
+
+/// Where probes leave from, as given by --interface, --source-ip and
+/// --source-port.
+fn source_of(opts: &Opts) -> std::io::Result<Source> {
+    let mut source = Source::default();
+    if let Some(name) = &opts.interface {
+        source = source.with_interface(name)?;
+    }
+    if let Some(ip) = opts.source_ip {
+        source = source.with_ip(ip);
+    }
+    if let Some(ports) = &opts.source_port {
+        source = source.with_ports(ports.start..=ports.end);
+    }
+    Ok(source)
+}
+
+/// Runs service detection on every open socket of the scan, reusing the
+/// banners grabbed during the scan. Probes leave from `source` too.
+fn detect_services(
+    report: &ScanReport,
+    opts: &Opts,
+    source: &Source,
+) -> Vec<(SocketAddr, ServiceMatch)> {
+    let probes = match &opts.service_probes {
+        Some(path) => match ServiceProbes::from_file(path) {
+            Ok(probes) => Arc::new(probes),
+            Err(e) => {
+                warning!(
+                    format!(
+                        "Could not load service probes from {}: {e}. Using the built-in ones.",
+                        path.display()
+                    ),
+                    opts.greppable,
+                    opts.accessible
+                );
+                ServiceProbes::embedded()
+            }
+        },
+        None => ServiceProbes::embedded(),
+    };
+    let detector = ServiceDetector::new(probes)
+        .with_intensity(opts.version_intensity)
+        .with_timeout(Duration::from_millis(opts.timeout.into()))
+        .with_source(source.clone());
+    let protocol = if opts.udp {
+        ProbeProtocol::Udp
+    } else {
+        ProbeProtocol::Tcp
+    };
+
+    let detections = report
+        .results
+        .iter()
+        .filter(|result| result.state.is_open())
+        .map(|result| {
+            let detector = &detector;
+            async move {
+                let service = detector
+                    .detect(result.socket, protocol, result.banner.as_ref())
+                    .await;
+                service.map(|service| (result.socket, service))
+            }
+        });
+    let mut services: Vec<_> = block_on(
+        futures::stream::iter(detections)
+            .buffer_unordered(SERVICE_DETECTION_CONCURRENCY)
+            .filter_map(|service| async move { service })
+            .collect(),
+    );
+    services.sort_by_key(|(socket, _)| *socket);
+    services
+}
+
+/// Shakes hands with the open ports that usually speak TLS, from `source`.
+fn inspect_tls(report: &ScanReport, opts: &Opts, source: &Source) -> Vec<(SocketAddr, TlsInfo)> {
+    let inspector = TlsInspector::new()
+        .with_timeout(Duration::from_millis(opts.timeout.into()))
+        .with_source(source.clone());
+    let handshakes = report
+        .open_sockets()
+        .filter(|socket| is_tls_port(socket.port()))
+        .map(|socket| {
+            let inspector = &inspector;
+            async move {
+                let info = inspector.inspect(socket, None).await.ok()?;
+                Some((socket, info))
+            }
+        });
+    let mut sessions: Vec<_> = block_on(
+        futures::stream::iter(handshakes)
+            .buffer_unordered(SERVICE_DETECTION_CONCURRENCY)
+            .filter_map(|session| async move { session })
+            .collect(),
+    );
+    sessions.sort_by_key(|(socket, _)| *socket);
+    sessions
+}
+
 /// Prints the opening title of RustScan
 #[allow(clippy::items_after_statements, clippy::needless_raw_string_hashes)]
 fn print_opening(opts: &Opts) {
@@ -2,14 +2,19 @@
 mod range_iterator;
 use crate::input::{PortRange, ScanOrder};
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 use range_iterator::RangeIterator;
+use serde::{Deserialize, Serialize};
 
 /// Represents options of port scanning.
 ///
 /// Right now all these options involve ranges, but in the future
 /// it will also contain custom lists of ports.
-#[derive(Debug)]
+///
+/// A strategy always gives the same order once picked, so it can be saved
+/// along with a scan to resume it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PortStrategy {
     Manual(Vec<u16>),
     Serial(SerialRange),
@@ -18,8 +23,21 @@ pub enum PortStrategy {
 
 impl PortStrategy {
     pub fn pick(range: &Option<PortRange>, ports: Option<Vec<u16>>, order: ScanOrder) -> Self {
+        Self::pick_with_seed(range, ports, order, rand::random())
+    }
+
+    /// Like `pick`, but a random order only depends on `seed`. Workers
+    /// sharing a scan need the same seed to agree on the order.
+    pub fn pick_with_seed(
+        range: &Option<PortRange>,
+        ports: Option<Vec<u16>>,
+        order: ScanOrder,
+        seed: u64,
+    ) -> Self {
         match order {
-            ScanOrder::Serial if ports.is_none() => {
+            // The scanner shuffles sockets itself, the ports can stay in
+            // order.
+            ScanOrder::Serial | ScanOrder::RandomSockets if ports.is_none() => {
                 let range = range.as_ref().unwrap();
                 PortStrategy::Serial(SerialRange {
                     start: range.start,
@@ -31,11 +49,12 @@ impl PortStrategy {
                 PortStrategy::Random(RandomRange {
                     start: range.start,
                     end: range.end,
+                    seed,
                 })
             }
-            ScanOrder::Serial => PortStrategy::Manual(ports.unwrap()),
+            ScanOrder::Serial | ScanOrder::RandomSockets => PortStrategy::Manual(ports.unwrap()),
             ScanOrder::Random => {
-                let mut rng = thread_rng();
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
                 let mut ports = ports.unwrap();
                 ports.shuffle(&mut rng);
                 PortStrategy::Manual(ports)
@@ -60,7 +79,7 @@ trait RangeOrder {
 
 /// As the name implies SerialRange will always generate a vector in
 /// ascending order.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SerialRange {
     start: u16,
     end: u16,
@@ -74,10 +93,12 @@ impl RangeOrder for SerialRange {
 
 /// As the name implies RandomRange will always generate a vector with
 /// a random order. This vector is built following the LCG algorithm.
-#[derive(Debug)]
+/// The order only depends on the seed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RandomRange {
     start: u16,
     end: u16,
+    seed: u64,
 }
 
 impl RangeOrder for RandomRange {
@@ -91,7 +112,7 @@ impl RangeOrder for RandomRange {
     // port numbers close to each other are pretty slim due to the way the
     // algorithm works.
     fn generate(&self) -> Vec<u16> {
-        RangeIterator::new(self.start.into(), self.end.into()).collect()
+        RangeIterator::with_seed(self.start.into(), self.end.into(), self.seed).collect()
     }
 }
 
@@ -120,6 +141,38 @@ mod tests {
         assert_eq!(expected_range, result);
     }
 
+    #[test]
+    fn random_strategy_keeps_its_order() {
+        let range = PortRange { start: 1, end: 100 };
+        let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
+        assert_eq!(strategy.order(), strategy.order());
+
+        let saved = serde_json::to_string(&strategy).unwrap();
+        let restored: PortStrategy = serde_json::from_str(&saved).unwrap();
+        assert_eq!(strategy.order(), restored.order());
+    }
+
+    #[test]
+    fn same_seed_same_random_order() {
+        let range = PortRange {
+            start: 1,
+            end: 1000,
+        };
+        let ports: Vec<u16> = (1..100).collect();
+        for (range, ports) in [(Some(range), None), (None, Some(ports))] {
+            let first = PortStrategy::pick_with_seed(&range, ports.clone(), ScanOrder::Random, 42);
+            let second = PortStrategy::pick_with_seed(&range, ports, ScanOrder::Random, 42);
+            assert_eq!(first.order(), second.order());
+        }
+    }
+
+    #[test]
+    fn seed_pins_the_port_order() {
+        let ports: Vec<u16> = (1..=10).collect();
+        let strategy = PortStrategy::pick_with_seed(&None, Some(ports), ScanOrder::Random, 42);
+        assert_eq!(strategy.order(), vec![1, 4, 6, 8, 10, 5, 9, 2, 7, 3]);
+    }
+
     #[test]
     fn serial_strategy_with_ports() {
         let strategy = PortStrategy::pick(&None, Some(vec![80, 443]), ScanOrder::Serial);
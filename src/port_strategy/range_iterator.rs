@@ -1,7 +1,12 @@
 use gcd::Gcd;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
 use std::convert::TryInto;
 
+/// The whole state of the iterator is plain data, so it can be saved with
+/// a checkpoint and picked up again later.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RangeIterator {
     active: bool,
     normalized_end: u32,
@@ -20,14 +25,15 @@ impl RangeIterator {
     /// which will server as the step for the algorithm.
     ///
     /// For example, the range `1000-2500` will be normalized to `0-1500`
-    /// before going through the algorithm.
-    pub fn new(start: u32, end: u32) -> Self {
+    /// before going through the algorithm. The same seed always gives the
+    /// same order, on every platform and with every build.
+    pub fn with_seed(start: u32, end: u32, seed: u64) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
         let normalized_end = end - start + 1;
-        let step = pick_random_coprime(normalized_end);
+        let step = pick_random_coprime(normalized_end, &mut rng);
 
         // Randomly choose a number within the range to be the first
         // and assign it as a pick.
-        let mut rng = rand::thread_rng();
         let normalized_first_pick = rng.gen_range(0..normalized_end);
 
         Self {
@@ -39,6 +45,11 @@ impl RangeIterator {
             actual_start: start,
         }
     }
+
+    #[cfg(test)]
+    pub fn new(start: u32, end: u32) -> Self {
+        Self::with_seed(start, end, rand::random())
+    }
 }
 
 impl Iterator for RangeIterator {
@@ -79,11 +90,10 @@ impl Iterator for RangeIterator {
 /// the boundaries, which in these case are the "start" and "end" arguments
 /// would also provide non-ideal randomization as discussed on the paragraph
 /// above.
-fn pick_random_coprime(end: u32) -> u32 {
+fn pick_random_coprime(end: u32, rng: &mut impl Rng) -> u32 {
     let range_boundary = end / 4;
     let lower_range = range_boundary;
     let upper_range = end - range_boundary;
-    let mut rng = rand::thread_rng();
     let mut candidate = rng.gen_range(lower_range..upper_range);
 
     for _ in 0..10 {
@@ -123,6 +133,33 @@ mod tests {
         assert_eq!(expected_range, result);
     }
 
+    #[test]
+    fn same_seed_same_order() {
+        let first: Vec<u16> = RangeIterator::with_seed(1, 1000, 42).collect();
+        let second: Vec<u16> = RangeIterator::with_seed(1, 1000, 42).collect();
+        assert_eq!(first, second);
+        let other: Vec<u16> = RangeIterator::with_seed(1, 1000, 43).collect();
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn seed_pins_the_order() {
+        let order: Vec<u16> = RangeIterator::with_seed(1, 10, 42).collect();
+        assert_eq!(order, vec![2, 5, 8, 1, 4, 7, 10, 3, 6, 9]);
+    }
+
+    #[test]
+    fn saved_state_carries_on() {
+        let mut range = RangeIterator::new(1, 100);
+        let head: Vec<u16> = range.by_ref().take(30).collect();
+        let saved = serde_json::to_string(&range).unwrap();
+        let restored: RangeIterator = serde_json::from_str(&saved).unwrap();
+
+        let mut all: Vec<u16> = head.into_iter().chain(restored).collect();
+        all.sort_unstable();
+        assert_eq!(all, (1..=100).collect::<Vec<u16>>());
+    }
+
     fn generate_sorted_range(start: u32, end: u32) -> Vec<u16> {
         let range = RangeIterator::new(start, end);
         let mut result = range.into_iter().collect::<Vec<u16>>();
@@ -0,0 +1,231 @@
+//! Active ARP sweeps over a raw Ethernet channel.
+//!
+//! Every host on the local link has to answer ARP, whatever its firewall
+//! does with IP, so this is the most reliable way to find out what is up
+//! on a directly attached subnet. Needs root or `CAP_NET_RAW`.
+use pnet_datalink::{Channel, MacAddr, NetworkInterface};
+use pnet_packet::arp::{ArpHardwareTypes, ArpOperations, ArpPacket, MutableArpPacket};
+use pnet_packet::ethernet::{EtherTypes, EthernetPacket, MutableEthernetPacket};
+use pnet_packet::{MutablePacket, Packet};
+use std::collections::HashMap;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Ethernet header plus an ARP request for IPv4.
+const REQUEST_LENGTH: usize = 14 + 28;
+const READ_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// A host that answered our ARP request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArpReply {
+    pub ip: Ipv4Addr,
+    pub mac: MacAddr,
+    pub interface: String,
+    pub rtt: Duration,
+}
+
+/// Groups `targets` by the interface they are directly attached to. Only
+/// the interface called `interface` is considered when given, targets that
+/// are not on-link are left out.
+pub fn group_by_interface(
+    targets: &[Ipv4Addr],
+    interface: Option<&str>,
+) -> Vec<(NetworkInterface, Vec<Ipv4Addr>)> {
+    let interfaces: Vec<NetworkInterface> = pnet_datalink::interfaces()
+        .into_iter()
+        .filter(|candidate| interface.is_none_or(|name| candidate.name == name))
+        .collect();
+    let mut groups: Vec<(NetworkInterface, Vec<Ipv4Addr>)> = Vec::new();
+    for &target in targets {
+        let Some(found) = interfaces
+            .iter()
+            .find(|candidate| source_on(candidate, target).is_some())
+        else {
+            continue;
+        };
+        match groups
+            .iter_mut()
+            .find(|(known, _)| known.name == found.name)
+        {
+            Some((_, group)) => group.push(target),
+            None => groups.push((found.clone(), vec![target])),
+        }
+    }
+    groups
+}
+
+/// Our address on the subnet of `interface` that holds `ip`, if `ip` is
+/// directly reachable there. Loopback, down and MAC-less interfaces are
+/// skipped.
+pub fn source_on(interface: &NetworkInterface, ip: Ipv4Addr) -> Option<Ipv4Addr> {
+    if !interface.is_up() || interface.is_loopback() || interface.mac.is_none() {
+        return None;
+    }
+    interface.ips.iter().find_map(|network| match network.ip() {
+        IpAddr::V4(source) if source != ip && network.contains(IpAddr::V4(ip)) => Some(source),
+        _ => None,
+    })
+}
+
+/// Asks every address of `targets` for its MAC on `interface` and waits
+/// `timeout` after the last request for the stragglers. Addresses outside
+/// the subnets of `interface` are ignored.
+///
+/// Blocks the calling thread for the whole sweep.
+pub fn arp_sweep(
+    interface: &NetworkInterface,
+    targets: &[Ipv4Addr],
+    timeout: Duration,
+) -> io::Result<Vec<ArpReply>> {
+    let Some(mac) = interface.mac else {
+        return Ok(Vec::new());
+    };
+    let config = pnet_datalink::Config {
+        read_timeout: Some(READ_TIMEOUT),
+        ..pnet_datalink::Config::default()
+    };
+    let (mut tx, mut rx) = match pnet_datalink::channel(interface, config)? {
+        Channel::Ethernet(tx, rx) => (tx, rx),
+        _ => return Err(io::Error::other("unsupported datalink channel")),
+    };
+
+    let stop = AtomicBool::new(false);
+    let (heard_tx, heard) = mpsc::channel();
+    thread::scope(|scope| {
+        // Replies are read on their own thread, so that large sweeps never
+        // wait on the receive timeout between two requests.
+        scope.spawn(|| {
+            while !stop.load(Ordering::Relaxed) {
+                match rx.next() {
+                    Ok(frame) => {
+                        if let Some(reply) = parse_reply(frame) {
+                            let _ = heard_tx.send((reply, Instant::now()));
+                        }
+                    }
+                    Err(e) if is_timeout(&e) => {}
+                    Err(_) => break,
+                }
+            }
+        });
+
+        let mut sent: HashMap<Ipv4Addr, Instant> = HashMap::new();
+        let mut result = Ok(());
+        for &target in targets {
+            let Some(source) = source_on(interface, target) else {
+                continue;
+            };
+            sent.insert(target, Instant::now());
+            if let Some(Err(e)) = tx.send_to(&request_frame(mac, source, target), None) {
+                result = Err(e);
+                break;
+            }
+        }
+
+        let mut replies: HashMap<Ipv4Addr, ArpReply> = HashMap::new();
+        let deadline = Instant::now() + timeout;
+        while result.is_ok() && replies.len() < sent.len() {
+            let left = deadline.saturating_duration_since(Instant::now());
+            let Ok(((ip, mac), at)) = heard.recv_timeout(left) else {
+                break;
+            };
+            if let Some(sent_at) = sent.get(&ip) {
+                replies.entry(ip).or_insert_with(|| ArpReply {
+                    ip,
+                    mac,
+                    interface: interface.name.clone(),
+                    rtt: at.saturating_duration_since(*sent_at),
+                });
+            }
+        }
+        stop.store(true, Ordering::Relaxed);
+
+        result.map(|()| {
+            let mut replies: Vec<ArpReply> = replies.into_values().collect();
+            replies.sort_by_key(|reply| reply.ip);
+            replies
+        })
+    })
+}
+
+fn is_timeout(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock
+    )
+}
+
+/// A broadcast who-has for `target`.
+fn request_frame(mac: MacAddr, source: Ipv4Addr, target: Ipv4Addr) -> Vec<u8> {
+    let mut buffer = vec![0; REQUEST_LENGTH];
+    let mut ethernet = MutableEthernetPacket::new(&mut buffer).unwrap();
+    ethernet.set_destination(MacAddr::broadcast());
+    ethernet.set_source(mac);
+    ethernet.set_ethertype(EtherTypes::Arp);
+
+    let mut arp = MutableArpPacket::new(ethernet.payload_mut()).unwrap();
+    arp.set_hardware_type(ArpHardwareTypes::Ethernet);
+    arp.set_protocol_type(EtherTypes::Ipv4);
+    arp.set_hw_addr_len(6);
+    arp.set_proto_addr_len(4);
+    arp.set_operation(ArpOperations::Request);
+    arp.set_sender_hw_addr(mac);
+    arp.set_sender_proto_addr(source);
+    arp.set_target_hw_addr(MacAddr::zero());
+    arp.set_target_proto_addr(target);
+    buffer
+}
+
+/// The address and MAC of the host an ARP reply comes from.
+fn parse_reply(frame: &[u8]) -> Option<(Ipv4Addr, MacAddr)> {
+    let ethernet = EthernetPacket::new(frame)?;
+    if ethernet.get_ethertype() != EtherTypes::Arp {
+        return None;
+    }
+    let arp = ArpPacket::new(ethernet.payload())?;
+    (arp.get_operation() == ArpOperations::Reply)
+        .then(|| (arp.get_sender_proto_addr(), arp.get_sender_hw_addr()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OURS: MacAddr = MacAddr(0x02, 0, 0, 0, 0, 1);
+    const THEIRS: MacAddr = MacAddr(0x02, 0, 0, 0, 0, 2);
+
+    #[test]
+    fn requests_are_broadcast_who_has() {
+        let frame = request_frame(OURS, Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 2));
+        let ethernet = EthernetPacket::new(&frame).unwrap();
+        assert_eq!(ethernet.get_destination(), MacAddr::broadcast());
+        assert_eq!(ethernet.get_ethertype(), EtherTypes::Arp);
+
+        let arp = ArpPacket::new(ethernet.payload()).unwrap();
+        assert_eq!(arp.get_operation(), ArpOperations::Request);
+        assert_eq!(arp.get_sender_hw_addr(), OURS);
+        assert_eq!(arp.get_target_proto_addr(), Ipv4Addr::new(10, 0, 0, 2));
+        // Our own request is not an answer.
+        assert_eq!(parse_reply(&frame), None);
+    }
+
+    #[test]
+    fn replies_name_the_sender() {
+        let mut frame = request_frame(
+            THEIRS,
+            Ipv4Addr::new(10, 0, 0, 2),
+            Ipv4Addr::new(10, 0, 0, 1),
+        );
+        let mut ethernet = MutableEthernetPacket::new(&mut frame).unwrap();
+        MutableArpPacket::new(ethernet.payload_mut())
+            .unwrap()
+            .set_operation(ArpOperations::Reply);
+        assert_eq!(
+            parse_reply(&frame),
+            Some((Ipv4Addr::new(10, 0, 0, 2), THEIRS))
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Banner grabbing on freshly connected TCP sockets.
+//!
+//! Most line based services greet first (SSH, FTP, SMTP, POP3, IMAP, MySQL,
+//! ...), so we start by listening. Services that wait for the client get a
+//! minimal nudge once they stayed silent, HTTP ports get theirs right away.
+use async_std::io;
+use futures::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+/// Ports where the client is expected to speak first with HTTP.
+pub const HTTP_PORTS: &[u16] = &[
+    80, 81, 591, 3000, 5000, 5601, 8000, 8008, 8080, 8081, 8088, 8888, 9000, 9200,
+];
+/// Ports that start with a TLS handshake, where a plaintext banner never comes.
+pub const TLS_PORTS: &[u16] = &[443, 465, 636, 853, 989, 990, 992, 993, 994, 995, 5986, 8443];
+
+const HTTP_NUDGE: &[u8] = b"HEAD / HTTP/1.0\r\n\r\n";
+/// Blank lines make most line based protocols answer, if only with an error.
+const GENERIC_NUDGE: &[u8] = b"\r\n\r\n";
+
+/// How long to wait for more data once the first chunk came in, as a
+/// fraction of the read timeout. Greetings rarely span several segments.
+const FOLLOW_UP_DIVISOR: u32 = 4;
+
+/// How the banner phase reads from open sockets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BannerGrab {
+    /// How long to wait for the service to say something.
+    pub timeout: Duration,
+    /// Reading stops after this many bytes.
+    pub max_bytes: usize,
+}
+
+impl Default for BannerGrab {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_millis(1000),
+            max_bytes: 1024,
+        }
+    }
+}
+
+/// What a service sent after we connected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Banner {
+    /// The raw bytes, at most `BannerGrab::max_bytes` of them.
+    pub data: Vec<u8>,
+    /// Whether the service only answered after a nudge.
+    pub nudged: bool,
+}
+
+impl Banner {
+    /// The banner as printable text: line breaks are kept, other control
+    /// characters and invalid UTF-8 are escaped.
+    pub fn text(&self) -> String {
+        let mut text = String::with_capacity(self.data.len());
+        for chunk in self.data.utf8_chunks() {
+            for c in chunk.valid().chars() {
+                match c {
+                    '\r' => {}
+                    '\n' | '\t' => text.push(c),
+                    c if c.is_control() => text.push_str(&c.escape_default().to_string()),
+                    c => text.push(c),
+                }
+            }
+            for byte in chunk.invalid() {
+                text.push_str(&format!("\\x{byte:02x}"));
+            }
+        }
+        text.trim().to_string()
+    }
+}
+
+/// Shows the first non-empty line, which is what identifies most services.
+impl fmt::Display for Banner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = self.text();
+        let line = text.lines().map(str::trim).find(|line| !line.is_empty());
+        f.write_str(line.unwrap_or_default())
+    }
+}
+
+/// Reads the banner of the service behind `stream`, connected to `port`.
+///
+/// Returns `None` when the service stayed silent, even after a nudge, and
+/// for TLS ports.
+pub async fn grab_banner<S>(stream: &mut S, port: u16, options: &BannerGrab) -> Option<Banner>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    if TLS_PORTS.contains(&port) {
+        return None;
+    }
+
+    let client_first = HTTP_PORTS.contains(&port);
+    if !client_first {
+        if let Some(data) = read_banner(stream, options).await {
+            return Some(Banner {
+                data,
+                nudged: false,
+            });
+        }
+    }
+
+    let nudge = if client_first {
+        HTTP_NUDGE
+    } else {
+        GENERIC_NUDGE
+    };
+    stream.write_all(nudge).await.ok()?;
+    let data = read_banner(stream, options).await?;
+    Some(Banner { data, nudged: true })
+}
+
+/// Reads until the byte cap, the end of the stream or a pause in the data.
+pub(crate) async fn read_banner<S>(stream: &mut S, options: &BannerGrab) -> Option<Vec<u8>>
+where
+    S: AsyncRead + Unpin,
+{
+    let mut data = vec![0; options.max_bytes];
+    let mut len = 0;
+    let mut wait = options.timeout;
+    while len < data.len() {
+        match io::timeout(wait, stream.read(&mut data[len..])).await {
+            Ok(0) | Err(_) => break,
+            Ok(read) => len += read,
+        }
+        wait = options.timeout / FOLLOW_UP_DIVISOR;
+    }
+    data.truncate(len);
+    (len > 0).then_some(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{grab_banner, Banner, BannerGrab};
+    use async_std::net::{TcpListener, TcpStream};
+    use async_std::task::block_on;
+    use futures::io::{AsyncReadExt, AsyncWriteExt};
+    use std::time::Duration;
+
+    const OPTIONS: BannerGrab = BannerGrab {
+        timeout: Duration::from_millis(300),
+        max_bytes: 64,
+    };
+
+    /// Connects to a loopback server running `serve` on the accepted stream.
+    async fn connect_to<F, Fut>(serve: F) -> TcpStream
+    where
+        F: FnOnce(TcpStream) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = ()> + Send,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        async_std::task::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            serve(stream).await;
+        });
+        TcpStream::connect(addr).await.unwrap()
+    }
+
+    #[test]
+    fn reads_server_first_greeting() {
+        block_on(async {
+            let mut stream = connect_to(|mut stream| async move {
+                stream.write_all(b"SSH-2.0-OpenSSH_9.6\r\n").await.unwrap();
+                async_std::task::sleep(Duration::from_secs(1)).await;
+            })
+            .await;
+
+            let banner = grab_banner(&mut stream, 22, &OPTIONS).await.unwrap();
+            assert!(!banner.nudged);
+            assert_eq!(banner.to_string(), "SSH-2.0-OpenSSH_9.6");
+        });
+    }
+
+    #[test]
+    fn nudges_silent_services() {
+        block_on(async {
+            let mut stream = connect_to(|mut stream| async move {
+                let mut request = [0; 4];
+                stream.read_exact(&mut request).await.unwrap();
+                stream.write_all(b"500 what?\r\n").await.unwrap();
+            })
+            .await;
+
+            let banner = grab_banner(&mut stream, 2121, &OPTIONS).await.unwrap();
+            assert!(banner.nudged);
+            assert_eq!(banner.to_string(), "500 what?");
+        });
+    }
+
+    #[test]
+    fn stops_at_the_byte_cap() {
+        block_on(async {
+            let mut stream = connect_to(|mut stream| async move {
+                stream.write_all(&[b'A'; 1000]).await.unwrap();
+            })
+            .await;
+
+            let banner = grab_banner(&mut stream, 21, &OPTIONS).await.unwrap();
+            assert_eq!(banner.data.len(), OPTIONS.max_bytes);
+        });
+    }
+
+    #[test]
+    fn text_escapes_binary_data() {
+        let banner = Banner {
+            data: b"\x4a\x00\x00\x00\x0a5.7.42\x00\xff".to_vec(),
+            nudged: false,
+        };
+        assert_eq!(banner.text(), "J\\u{0}\\u{0}\\u{0}\n5.7.42\\u{0}\\xff");
+    }
+}
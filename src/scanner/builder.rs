@@ -0,0 +1,337 @@
+//! Setting up a `Scanner` by name rather than by position.
+#[cfg(unix)]
+use super::SynScanner;
+use super::{
+    BannerGrab, CancelToken, Checkpoint, OsTransport, Scanner, Shard, Source, Transport,
+    UdpPayloads,
+};
+use crate::input::{PortRange, ScanOrder};
+use crate::port_strategy::PortStrategy;
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::num::NonZeroU8;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Builds a `Scanner` for a list of hosts. Anything not set keeps the
+/// defaults of the command line: every TCP port in serial order, batches
+/// of 4500 sockets, a 1.5s timeout and a single try, from wherever the OS
+/// picks.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use SCANNR::scanner::Scanner;
+/// let ips = vec!["127.0.0.1".parse().unwrap()];
+/// let scanner = Scanner::builder(&ips)
+///     .with_ports(vec![22, 80, 443])
+///     .with_timeout(Duration::from_millis(500))
+///     .with_max_rate(1000)
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct ScannerBuilder<T = OsTransport> {
+    /// What `build` hands out, filled in as the options come.
+    scanner: Scanner<T>,
+}
+
+impl ScannerBuilder {
+    pub fn new(ips: &[IpAddr]) -> Self {
+        let every_port = PortRange {
+            start: 1,
+            end: u16::MAX,
+        };
+        Self {
+            scanner: Scanner {
+                ips: ips.to_vec(),
+                batch_size: 4500,
+                timeout: Duration::from_millis(1500),
+                tries: NonZeroU8::MIN,
+                port_strategy: PortStrategy::pick(&Some(every_port), None, ScanOrder::Serial),
+                exclude_ports: vec![],
+                udp: false,
+                cancel: None,
+                deadline: None,
+                host_deadline: None,
+                dead_host_threshold: None,
+                live_hosts: HashSet::new(),
+                adaptive: false,
+                max_rate: None,
+                max_per_host: None,
+                max_per_subnet: None,
+                timeout_bounds: None,
+                banner_grab: None,
+                #[cfg(unix)]
+                syn: None,
+                udp_payloads: None,
+                checkpoint: None,
+                resume: None,
+                shard: None,
+                socket_seed: None,
+                all_results: false,
+                transport: OsTransport::default(),
+            },
+        }
+    }
+
+    /// Sends connect and UDP probes from `source` instead of letting the
+    /// OS pick. SYN probes are set up with `SynScanner::from_source`.
+    pub fn with_source(mut self, source: Source) -> Self {
+        self.scanner.transport = OsTransport::new(source);
+        self
+    }
+}
+
+impl<T: Transport> ScannerBuilder<T> {
+    /// Which ports to scan, and in which order.
+    pub fn with_port_strategy(mut self, port_strategy: PortStrategy) -> Self {
+        self.scanner.port_strategy = port_strategy;
+        self
+    }
+
+    /// Scans exactly `ports`, in that order.
+    pub fn with_ports(self, ports: Vec<u16>) -> Self {
+        self.with_port_strategy(PortStrategy::pick(&None, Some(ports), ScanOrder::Serial))
+    }
+
+    /// How many sockets are scanned at once, see `with_adaptive`.
+    pub fn with_batch_size(mut self, batch_size: u16) -> Self {
+        self.scanner.batch_size = batch_size;
+        self
+    }
+
+    /// How long to wait for an answer before trying again.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.scanner.timeout = timeout;
+        self
+    }
+
+    /// How many times a socket that does not answer is probed. Zero counts
+    /// as one.
+    pub fn with_tries(mut self, tries: u8) -> Self {
+        self.scanner.tries = NonZeroU8::new(tries).unwrap_or(NonZeroU8::MIN);
+        self
+    }
+
+    /// Ports left out of the scan, whatever the port strategy says.
+    pub fn with_exclude_ports(mut self, exclude_ports: Vec<u16>) -> Self {
+        self.scanner.exclude_ports = exclude_ports;
+        self
+    }
+
+    /// Scans UDP ports instead of TCP ones.
+    pub fn with_udp(mut self, udp: bool) -> Self {
+        self.scanner.udp = udp;
+        self
+    }
+
+    /// Stops the scan once `token` is cancelled. No new sockets are queued,
+    /// in-flight probes are dropped and the partial results are returned.
+    pub fn with_cancel_token(mut self, token: CancelToken) -> Self {
+        self.scanner.cancel = Some(token);
+        self
+    }
+
+    /// Stops the whole scan once it has been running for `deadline`.
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.scanner.deadline = Some(deadline);
+        self
+    }
+
+    /// Gives up on a host once `deadline` has passed since its first probe.
+    /// Its remaining sockets are reported as unscanned.
+    pub fn with_host_deadline(mut self, deadline: Duration) -> Self {
+        self.scanner.host_deadline = Some(deadline);
+        self
+    }
+
+    /// Judges a host down once its first `probes` sockets are scanned
+    /// without a single answer from the host itself, no accepted connect,
+    /// no refusal, and skips its remaining sockets. Timeouts, ICMP
+    /// unreachables from routers and silent UDP ports are no answer.
+    ///
+    /// Skipped hosts end up in `ScanReport::skipped_hosts`, their sockets
+    /// are reported as unscanned.
+    pub fn with_dead_host_threshold(mut self, probes: usize) -> Self {
+        self.scanner.dead_host_threshold = Some(probes.max(1));
+        self
+    }
+
+    /// Hosts known to be up, e.g. because they answered host discovery.
+    /// They are never judged down, see `with_dead_host_threshold`.
+    pub fn with_live_hosts(mut self, ips: &[IpAddr]) -> Self {
+        self.scanner.live_hosts = ips.iter().copied().collect();
+        self
+    }
+
+    /// Adapts the number of sockets scanned at once to how the network
+    /// copes, like TCP congestion control. `batch_size` becomes the upper
+    /// bound instead of a fixed value.
+    pub fn with_adaptive(mut self, adaptive: bool) -> Self {
+        self.scanner.adaptive = adaptive;
+        self
+    }
+
+    /// Sends at most `rate` probes per second, evenly spaced. Every try of
+    /// a socket counts as a probe.
+    pub fn with_max_rate(mut self, rate: u32) -> Self {
+        self.scanner.max_rate = Some(rate);
+        self
+    }
+
+    /// Never has more than `limit` probes in flight to the same host.
+    pub fn with_max_per_host(mut self, limit: usize) -> Self {
+        self.scanner.max_per_host = Some(limit);
+        self
+    }
+
+    /// Never has more than `limit` probes in flight to the same IPv4 /24 or
+    /// IPv6 /64.
+    pub fn with_max_per_subnet(mut self, limit: usize) -> Self {
+        self.scanner.max_per_subnet = Some(limit);
+        self
+    }
+
+    /// Derives the timeout of every host from its measured RTTs, bounded by
+    /// `min` and `max`. The regular timeout is used until a host answers.
+    pub fn with_dynamic_timeout(mut self, min: Duration, max: Duration) -> Self {
+        self.scanner.timeout_bounds = Some((min, max.max(min)));
+        self
+    }
+
+    /// Reads the greeting of every open TCP socket before closing it, see
+    /// `ScanResult::banner`.
+    pub fn with_banner_grab(mut self, options: BannerGrab) -> Self {
+        self.scanner.banner_grab = Some(options);
+        self
+    }
+
+    /// Scans TCP ports of IPv4 hosts with SYN probes sent by `syn`
+    /// instead of full connects. Open ports are never connected to, so no
+    /// banner is grabbed. IPv6 hosts are still connected to.
+    #[cfg(unix)]
+    pub fn with_syn(mut self, syn: SynScanner) -> Self {
+        self.scanner.syn = Some(syn);
+        self
+    }
+
+    /// Sends `payloads` to UDP ports instead of the built-in ones.
+    pub fn with_udp_payloads(mut self, payloads: UdpPayloads) -> Self {
+        self.scanner.udp_payloads = Some(payloads);
+        self
+    }
+
+    /// Saves the progress of the scan to `path` every few seconds and once
+    /// it is over, see `with_resume`.
+    pub fn with_checkpoint(mut self, path: PathBuf) -> Self {
+        self.scanner.checkpoint = Some(path);
+        self
+    }
+
+    /// Only scans the sockets of `shard`. Every worker of a sharded scan
+    /// needs the same hosts, in the same order, and the same port strategy,
+    /// see `PortStrategy::pick_with_seed`.
+    pub fn with_shard(mut self, shard: Shard) -> Self {
+        self.scanner.shard = Some(shard);
+        self
+    }
+
+    /// Scans the IP and port pairs in an order only `seed` decides, rather
+    /// than every IP on one port before moving on to the next port. The
+    /// port strategy still decides which ports there are.
+    pub fn with_shuffled_sockets(mut self, seed: u64) -> Self {
+        self.scanner.socket_seed = Some(seed);
+        self
+    }
+
+    /// Keeps every socket in `ScanReport::results`, not only the open ones.
+    /// The other states are always counted in the summary and sent as
+    /// events, keeping them all costs memory on large scans.
+    pub fn with_all_results(mut self, all_results: bool) -> Self {
+        self.scanner.all_results = all_results;
+        self
+    }
+
+    /// Sends the connect and UDP probes over `transport`, like a proxy or
+    /// a `SimulatedNetwork`. SYN probes keep going out of raw sockets.
+    pub fn with_transport<U: Transport>(self, transport: U) -> ScannerBuilder<U> {
+        let scanner = self.scanner;
+        ScannerBuilder {
+            scanner: Scanner {
+                ips: scanner.ips,
+                batch_size: scanner.batch_size,
+                timeout: scanner.timeout,
+                tries: scanner.tries,
+                port_strategy: scanner.port_strategy,
+                exclude_ports: scanner.exclude_ports,
+                udp: scanner.udp,
+                cancel: scanner.cancel,
+                deadline: scanner.deadline,
+                host_deadline: scanner.host_deadline,
+                dead_host_threshold: scanner.dead_host_threshold,
+                live_hosts: scanner.live_hosts,
+                adaptive: scanner.adaptive,
+                max_rate: scanner.max_rate,
+                max_per_host: scanner.max_per_host,
+                max_per_subnet: scanner.max_per_subnet,
+                timeout_bounds: scanner.timeout_bounds,
+                banner_grab: scanner.banner_grab,
+                #[cfg(unix)]
+                syn: scanner.syn,
+                udp_payloads: scanner.udp_payloads,
+                checkpoint: scanner.checkpoint,
+                resume: scanner.resume,
+                shard: scanner.shard,
+                socket_seed: scanner.socket_seed,
+                all_results: scanner.all_results,
+                transport,
+            },
+        }
+    }
+
+    /// Picks up the scan saved in `checkpoint`. Its hosts, ports, protocol,
+    /// shard and order replace those set so far, the sockets it already went
+    /// through are skipped and its results are reported again.
+    pub fn with_resume(mut self, checkpoint: Checkpoint) -> Self {
+        self.scanner.ips.clone_from(&checkpoint.ips);
+        self.scanner.port_strategy = checkpoint.port_strategy.clone();
+        self.scanner
+            .exclude_ports
+            .clone_from(&checkpoint.exclude_ports);
+        self.scanner.udp = checkpoint.udp;
+        self.scanner.shard = checkpoint.shard;
+        self.scanner.socket_seed = checkpoint.socket_seed;
+        self.scanner.resume = Some(checkpoint);
+        self
+    }
+
+    pub fn build(self) -> Scanner<T> {
+        self.scanner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scanner::Scanner;
+    use std::net::IpAddr;
+    use std::time::Duration;
+
+    #[test]
+    fn unset_options_keep_the_defaults() {
+        let ips: Vec<IpAddr> = vec!["127.0.0.1".parse().unwrap()];
+        let scanner = Scanner::builder(&ips).build();
+        assert_eq!(scanner.port_strategy.order().len(), 65_535);
+        assert_eq!(scanner.batch_size, 4500);
+        assert_eq!(scanner.timeout, Duration::from_millis(1500));
+        assert_eq!(scanner.tries.get(), 1);
+        assert!(!scanner.udp);
+
+        let scanner = Scanner::builder(&ips)
+            .with_ports(vec![443, 22])
+            .with_tries(0)
+            .with_udp(true)
+            .build();
+        assert_eq!(scanner.port_strategy.order(), vec![443, 22]);
+        assert_eq!(scanner.tries.get(), 1);
+        assert!(scanner.udp);
+    }
+}
@@ -0,0 +1,68 @@
+//! Cooperative cancellation for running scans.
+use async_channel::{Receiver, Sender};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A cheap, cloneable handle used to stop a running scan.
+///
+/// Every clone observes the same state, so a frontend can keep one clone
+/// (e.g. in a Ctrl-C handler or behind an "abort" button) and hand another
+/// to the `Scanner`. Cancelling is idempotent.
+#[derive(Debug, Clone)]
+pub struct CancelToken {
+    flag: Arc<AtomicBool>,
+    // Nothing is ever sent on this channel, closing it wakes up every
+    // task waiting in `cancelled()`.
+    closer: Sender<()>,
+    waiter: Receiver<()>,
+}
+
+impl Default for CancelToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        let (closer, waiter) = async_channel::bounded(1);
+        Self {
+            flag: Arc::new(AtomicBool::new(false)),
+            closer,
+            waiter,
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+        self.closer.close();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Relaxed)
+    }
+
+    /// Resolves once `cancel` has been called on any clone of this token.
+    pub async fn cancelled(&self) {
+        // recv only returns once the channel is closed.
+        let _ = self.waiter.recv().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CancelToken;
+    use async_std::task::block_on;
+
+    #[test]
+    fn clones_share_cancellation() {
+        let token = CancelToken::new();
+        let handle = token.clone();
+        assert!(!token.is_cancelled());
+
+        handle.cancel();
+        assert!(token.is_cancelled());
+        // Must not hang once cancelled.
+        block_on(token.cancelled());
+    }
+}
@@ -0,0 +1,199 @@
+//! Saving the progress of a scan to disk, to resume it after a crash or
+//! an interrupt.
+use super::{ScanResult, Shard};
+use crate::port_strategy::PortStrategy;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+
+/// Bumped whenever the layout of the file changes.
+pub(crate) const CHECKPOINT_VERSION: u32 = 1;
+
+/// Everything needed to pick a scan up where it stopped.
+///
+/// Sockets are numbered in scan order, see `SocketIterator`. Only open
+/// sockets are kept in `results`, the others are not worth the disk space
+/// on large scans.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub version: u32,
+    pub ips: Vec<IpAddr>,
+    pub port_strategy: PortStrategy,
+    pub exclude_ports: Vec<u16>,
+    pub udp: bool,
+    /// The part of the scan this worker took on, if it was split.
+    pub shard: Option<Shard>,
+    /// Seed of the socket order, for scans with shuffled sockets.
+    pub socket_seed: Option<u64>,
+    /// Every socket before this one is done.
+    pub position: usize,
+    /// Sockets at or after `position` that are done too. Probes finish out
+    /// of order, so there are always a few.
+    pub done: Vec<usize>,
+    pub results: Vec<ScanResult>,
+}
+
+impl Checkpoint {
+    pub fn load(path: &Path) -> Result<Self> {
+        let data = fs::read(path)
+            .with_context(|| format!("could not read checkpoint {}", path.display()))?;
+        let checkpoint: Self = serde_json::from_slice(&data)
+            .with_context(|| format!("{} is not a scan checkpoint", path.display()))?;
+        if checkpoint.version != CHECKPOINT_VERSION {
+            bail!(
+                "{} was written by an incompatible version (format {}, expected {})",
+                path.display(),
+                checkpoint.version,
+                CHECKPOINT_VERSION
+            );
+        }
+        Ok(checkpoint)
+    }
+
+    /// Writes the checkpoint next to `path` first and then moves it in
+    /// place, so a crash while saving never leaves a truncated file behind.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut staging = path.as_os_str().to_owned();
+        staging.push(".tmp");
+        fs::write(&staging, serde_json::to_vec(self)?)?;
+        fs::rename(&staging, path)
+    }
+}
+
+/// Which sockets of the scan order are done, as far as checkpoints go.
+#[derive(Debug, Default)]
+pub(crate) struct Progress {
+    /// Sockets handed out by the iterator and not done yet, by index.
+    pending: HashMap<SocketAddr, usize>,
+    pending_indices: BTreeSet<usize>,
+    /// Done sockets that come after the oldest pending one.
+    done: BTreeSet<usize>,
+    /// One past the last socket handed out.
+    next: usize,
+}
+
+impl Progress {
+    pub(crate) fn resume(checkpoint: &Checkpoint) -> Self {
+        Self {
+            done: checkpoint.done.iter().copied().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Whether a resumed scan already went through this socket.
+    pub(crate) fn is_done(&self, index: usize) -> bool {
+        self.done.contains(&index)
+    }
+
+    pub(crate) fn start(&mut self, socket: SocketAddr, index: usize) {
+        self.pending.insert(socket, index);
+        self.pending_indices.insert(index);
+        self.next = self.next.max(index + 1);
+    }
+
+    /// Marks `socket` done. Only the done sockets after the oldest pending
+    /// one are kept, so this stays as small as the probes in flight
+    /// whether or not anything is saved.
+    pub(crate) fn finish(&mut self, socket: SocketAddr) {
+        if let Some(index) = self.pending.remove(&socket) {
+            self.pending_indices.remove(&index);
+            self.done.insert(index);
+            let low = self.pending_indices.first().copied().unwrap_or(self.next);
+            while self.done.first().is_some_and(|&done| done < low) {
+                self.done.pop_first();
+            }
+        }
+    }
+
+    /// The position and done sockets to save, given the position of the
+    /// iterator. Sockets that are still pending will be scanned again.
+    pub(crate) fn snapshot(&mut self, position: usize) -> (usize, Vec<usize>) {
+        let low = self.pending_indices.first().copied().unwrap_or(position);
+        self.done = self.done.split_off(&low);
+        (low, self.done.iter().copied().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Checkpoint, Progress, CHECKPOINT_VERSION};
+    use crate::input::{PortRange, ScanOrder};
+    use crate::port_strategy::PortStrategy;
+    use crate::scanner::{PortState, ScanResult, Shard};
+    use std::net::SocketAddr;
+    use std::time::Duration;
+
+    fn socket(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    #[test]
+    fn out_of_order_probes_keep_the_oldest_pending() {
+        let mut progress = Progress::default();
+        for index in 0..5 {
+            progress.start(socket(index as u16), index);
+        }
+        progress.finish(socket(0));
+        progress.finish(socket(2));
+        progress.finish(socket(4));
+        assert_eq!(progress.snapshot(5), (1, vec![2, 4]));
+
+        progress.finish(socket(1));
+        progress.finish(socket(3));
+        assert_eq!(progress.snapshot(5), (5, vec![]));
+    }
+
+    #[test]
+    fn done_sockets_do_not_pile_up() {
+        let mut progress = Progress::default();
+        // Batches of probes that come back in the reverse order.
+        for batch in 0..600 {
+            let indices = batch * 100..(batch + 1) * 100;
+            for index in indices.clone() {
+                progress.start(socket(index as u16), index);
+            }
+            for index in indices.rev() {
+                progress.finish(socket(index as u16));
+                assert!(progress.done.len() < 100, "{index}");
+            }
+            assert!(progress.done.is_empty());
+        }
+    }
+
+    #[test]
+    fn saved_checkpoints_load_back() {
+        let path =
+            std::env::temp_dir().join(format!("scannr-checkpoint-{}.json", std::process::id()));
+        let checkpoint = Checkpoint {
+            version: CHECKPOINT_VERSION,
+            ips: vec!["10.0.0.1".parse().unwrap()],
+            port_strategy: PortStrategy::pick(
+                &Some(PortRange {
+                    start: 1,
+                    end: 1000,
+                }),
+                None,
+                ScanOrder::Random,
+            ),
+            exclude_ports: vec![9],
+            udp: false,
+            shard: Some(Shard { index: 2, count: 3 }),
+            socket_seed: Some(7),
+            position: 40,
+            done: vec![41, 45],
+            results: vec![
+                ScanResult::new(socket(22), PortState::Open).with_rtt(Duration::from_millis(3))
+            ],
+        };
+        checkpoint.save(&path).unwrap();
+        assert_eq!(Checkpoint::load(&path).unwrap(), checkpoint);
+
+        std::fs::write(&path, "{}").unwrap();
+        assert!(Checkpoint::load(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
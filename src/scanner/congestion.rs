@@ -0,0 +1,240 @@
+//! Congestion control for the number of sockets scanned at once.
+//!
+//! In adaptive mode the window follows the usual AIMD scheme: it grows while
+//! targets keep answering promptly and is halved when timeouts start to pile
+//! up. Running out of file descriptors shrinks the window in every mode, so
+//! a batch size that is too large for the OS slows the scan down instead of
+//! aborting it.
+use super::{PortState, ScanErrorKind, ScanResult};
+use log::debug;
+use std::io;
+use std::time::Duration;
+
+/// The window never shrinks below this.
+const MIN_WINDOW: usize = 1;
+/// Where an adaptive scan starts, before anything is known about the network.
+const INITIAL_WINDOW: usize = 64;
+/// How much the timeout ratio may grow from one round to the next before it
+/// is blamed on us rather than on filtered ports.
+const TIMEOUT_RATIO_SLACK: f64 = 0.1;
+/// An answer this many times slower than the fastest one seen means queues
+/// are building up somewhere, so the window stops growing.
+const RTT_INFLATION: u32 = 4;
+
+/// What a finished probe tells us about congestion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Signal {
+    /// The target answered, after `rtt` when it is known.
+    Answered(Option<Duration>),
+    /// Nothing came back before the timeout.
+    TimedOut,
+    /// The OS refused to give us another socket.
+    ResourceExhausted,
+}
+
+impl Signal {
+    /// Returns `None` for errors that say nothing about congestion.
+    pub(crate) fn from_outcome(outcome: &io::Result<ScanResult>) -> Option<Self> {
+        match outcome {
+            Ok(result) => match result.state {
+                PortState::Filtered | PortState::OpenFiltered => Some(Signal::TimedOut),
+                _ => Some(Signal::Answered(result.rtt)),
+            },
+            Err(e) if ScanErrorKind::from_error(e) == ScanErrorKind::ResourceExhausted => {
+                Some(Signal::ResourceExhausted)
+            }
+            Err(_) => None,
+        }
+    }
+}
+
+/// How many probes may be in flight at once.
+#[derive(Debug)]
+pub(crate) struct CongestionWindow {
+    adaptive: bool,
+    window: f64,
+    ceiling: usize,
+    slow_start_threshold: f64,
+    min_rtt: Option<Duration>,
+    round_probes: usize,
+    round_timeouts: usize,
+    last_timeout_ratio: Option<f64>,
+}
+
+impl CongestionWindow {
+    /// A window that stays at `size`, unless the OS runs out of sockets.
+    pub(crate) fn fixed(size: usize) -> Self {
+        let size = size.max(MIN_WINDOW);
+        Self::with_window(false, size as f64, size)
+    }
+
+    /// A window that starts small and adapts to the network, up to `ceiling`.
+    pub(crate) fn adaptive(ceiling: usize) -> Self {
+        let ceiling = ceiling.max(MIN_WINDOW);
+        Self::with_window(true, INITIAL_WINDOW.min(ceiling) as f64, ceiling)
+    }
+
+    fn with_window(adaptive: bool, window: f64, ceiling: usize) -> Self {
+        Self {
+            adaptive,
+            window,
+            ceiling,
+            slow_start_threshold: ceiling as f64,
+            min_rtt: None,
+            round_probes: 0,
+            round_timeouts: 0,
+            last_timeout_ratio: None,
+        }
+    }
+
+    /// The number of probes that may currently be in flight.
+    pub(crate) fn limit(&self) -> usize {
+        (self.window as usize).clamp(MIN_WINDOW, self.ceiling)
+    }
+
+    /// Updates the window with the outcome of one probe, `in_flight` being the
+    /// number of probes still running.
+    pub(crate) fn record(&mut self, signal: Signal, in_flight: usize) {
+        let before = self.limit();
+
+        match signal {
+            Signal::ResourceExhausted => {
+                // The OS could not handle one more socket than we have open.
+                self.ceiling = in_flight.max(MIN_WINDOW);
+                if self.adaptive {
+                    self.back_off();
+                } else {
+                    self.window = self.ceiling as f64;
+                }
+            }
+            _ if !self.adaptive => {}
+            Signal::Answered(rtt) => {
+                self.round_probes += 1;
+                if !self.rtt_inflated(rtt) {
+                    self.grow();
+                }
+                self.end_round();
+            }
+            Signal::TimedOut => {
+                self.round_probes += 1;
+                self.round_timeouts += 1;
+                self.end_round();
+            }
+        }
+
+        if self.limit() != before {
+            debug!("Congestion window {} -> {}", before, self.limit());
+        }
+    }
+
+    fn grow(&mut self) {
+        if self.window < self.slow_start_threshold {
+            self.window += 1.0;
+        } else {
+            self.window += 1.0 / self.window;
+        }
+        self.window = self.window.min(self.ceiling as f64);
+    }
+
+    fn back_off(&mut self) {
+        self.slow_start_threshold = (self.window / 2.0).max(MIN_WINDOW as f64);
+        self.window = self.slow_start_threshold;
+    }
+
+    /// Also keeps track of the fastest answer seen so far.
+    fn rtt_inflated(&mut self, rtt: Option<Duration>) -> bool {
+        let Some(rtt) = rtt else {
+            return false;
+        };
+        let min_rtt = *self.min_rtt.get_or_insert(rtt);
+        if rtt < min_rtt {
+            self.min_rtt = Some(rtt);
+            return false;
+        }
+        rtt > min_rtt * RTT_INFLATION
+    }
+
+    /// A round is one window worth of probes. Timeouts only count as loss when
+    /// they grow from one round to the next: a steady share of timeouts is
+    /// just what filtered ports look like.
+    fn end_round(&mut self) {
+        if self.round_probes < self.limit() {
+            return;
+        }
+
+        let ratio = self.round_timeouts as f64 / self.round_probes as f64;
+        if self
+            .last_timeout_ratio
+            .is_some_and(|last| ratio > last + TIMEOUT_RATIO_SLACK)
+        {
+            self.back_off();
+        }
+        self.last_timeout_ratio = Some(ratio);
+        self.round_probes = 0;
+        self.round_timeouts = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CongestionWindow, Signal, INITIAL_WINDOW};
+    use std::time::Duration;
+
+    fn answered() -> Signal {
+        Signal::Answered(Some(Duration::from_millis(10)))
+    }
+
+    #[test]
+    fn grows_while_targets_answer() {
+        let mut window = CongestionWindow::adaptive(1000);
+        assert_eq!(window.limit(), INITIAL_WINDOW);
+
+        for _ in 0..100 {
+            window.record(answered(), 0);
+        }
+        assert!(window.limit() > INITIAL_WINDOW);
+        assert!(window.limit() <= 1000);
+    }
+
+    #[test]
+    fn rising_timeouts_halve_the_window() {
+        let mut window = CongestionWindow::adaptive(INITIAL_WINDOW);
+        for _ in 0..INITIAL_WINDOW {
+            window.record(answered(), 0);
+        }
+        for _ in 0..INITIAL_WINDOW {
+            window.record(Signal::TimedOut, 0);
+        }
+        assert_eq!(window.limit(), INITIAL_WINDOW / 2);
+    }
+
+    #[test]
+    fn steady_timeouts_are_not_congestion() {
+        let mut window = CongestionWindow::adaptive(INITIAL_WINDOW);
+        for _ in 0..INITIAL_WINDOW * 4 {
+            window.record(Signal::TimedOut, 0);
+        }
+        assert_eq!(window.limit(), INITIAL_WINDOW);
+    }
+
+    #[test]
+    fn slow_answers_stop_growth() {
+        let mut window = CongestionWindow::adaptive(1000);
+        window.record(answered(), 0);
+        let limit = window.limit();
+        for _ in 0..10 {
+            window.record(Signal::Answered(Some(Duration::from_secs(1))), 0);
+        }
+        assert_eq!(window.limit(), limit);
+    }
+
+    #[test]
+    fn exhaustion_caps_even_a_fixed_window() {
+        let mut window = CongestionWindow::fixed(5000);
+        window.record(Signal::TimedOut, 0);
+        assert_eq!(window.limit(), 5000);
+
+        window.record(Signal::ResourceExhausted, 1000);
+        assert_eq!(window.limit(), 1000);
+    }
+}
@@ -0,0 +1,597 @@
+//! Finds out which hosts are up before their ports are scanned.
+//!
+//! On a sparse network most addresses have nothing behind them, and every
+//! port of those would only come back filtered after the full timeout. A
+//! handful of cheap probes per host tells the live ones apart first.
+#[cfg(unix)]
+use super::arp::{self, ArpReply};
+#[cfg(unix)]
+use super::icmp::{IcmpPinger, Ping};
+#[cfg(unix)]
+use super::syn::{Reply, SynScanner};
+use super::{CancelToken, Source};
+use async_std::io;
+use futures::stream::{self, FuturesUnordered, StreamExt};
+use log::debug;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Where TCP pings go when no port is given.
+const DEFAULT_TCP_PORT: u16 = 80;
+/// Where UDP pings go when no port is given, a port that is closed almost
+/// everywhere so that the host answers with port unreachable.
+const DEFAULT_UDP_PORT: u16 = 40125;
+
+/// One way of asking a host whether it is up.
+///
+/// Parsed from `echo`, `timestamp`, `arp`, or `syn`, `ack` and `udp`
+/// optionally followed by ports, like `syn:22,443`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiscoveryMethod {
+    /// ICMP echo request, what ping sends.
+    IcmpEcho,
+    /// ICMP timestamp request, often let through where echo is blocked.
+    IcmpTimestamp,
+    /// SYN to each port, any answer means the host is up.
+    TcpSyn(Vec<u16>),
+    /// Lone ACK to each port, answered with a RST by live hosts.
+    TcpAck(Vec<u16>),
+    /// Empty datagram to each port, answered by the service or with port
+    /// unreachable.
+    Udp(Vec<u16>),
+    /// ARP request, for hosts on a directly attached subnet.
+    Arp,
+}
+
+impl DiscoveryMethod {
+    /// What `HostDiscovery` uses when given no methods: nmap's default set
+    /// plus ARP for the local subnets.
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::Arp,
+            Self::IcmpEcho,
+            Self::TcpSyn(vec![443]),
+            Self::TcpAck(vec![80]),
+            Self::IcmpTimestamp,
+        ]
+    }
+
+    /// Whether the method cannot work at all without raw sockets. TCP
+    /// pings fall back to connects instead.
+    fn needs_raw_sockets(&self) -> bool {
+        matches!(self, Self::IcmpEcho | Self::IcmpTimestamp | Self::Arp)
+    }
+}
+
+impl FromStr for DiscoveryMethod {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (name, ports) = match input.split_once(':') {
+            Some((name, ports)) => (name, Some(ports)),
+            None => (input, None),
+        };
+        let port_list = |default: u16| -> Result<Vec<u16>, String> {
+            let Some(ports) = ports else {
+                return Ok(vec![default]);
+            };
+            ports
+                .split(',')
+                .map(|port| port.parse().map_err(|_| format!("bad port {port:?}")))
+                .collect()
+        };
+        let method = match name.to_ascii_lowercase().as_str() {
+            "echo" => Self::IcmpEcho,
+            "timestamp" => Self::IcmpTimestamp,
+            "arp" => Self::Arp,
+            "syn" => Self::TcpSyn(port_list(DEFAULT_TCP_PORT)?),
+            "ack" => Self::TcpAck(port_list(DEFAULT_TCP_PORT)?),
+            "udp" => Self::Udp(port_list(DEFAULT_UDP_PORT)?),
+            _ => {
+                return Err(format!(
+                "unknown discovery method {name:?}, expected echo, timestamp, arp, syn, ack or udp"
+            ))
+            }
+        };
+        if ports.is_some() && !matches!(method, Self::TcpSyn(_) | Self::TcpAck(_) | Self::Udp(_)) {
+            return Err(format!("{name} does not take ports"));
+        }
+        Ok(method)
+    }
+}
+
+impl fmt::Display for DiscoveryMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, ports) = match self {
+            Self::IcmpEcho => return f.write_str("echo"),
+            Self::IcmpTimestamp => return f.write_str("timestamp"),
+            Self::Arp => return f.write_str("arp"),
+            Self::TcpSyn(ports) => ("syn", ports),
+            Self::TcpAck(ports) => ("ack", ports),
+            Self::Udp(ports) => ("udp", ports),
+        };
+        let ports: Vec<String> = ports.iter().map(ToString::to_string).collect();
+        write!(f, "{name}:{}", ports.join(","))
+    }
+}
+
+/// A host that answered one of the discovery probes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveHost {
+    pub ip: IpAddr,
+    /// What gave it away, like `echo-reply` or `syn-ack 443`.
+    pub reason: String,
+    pub rtt: Option<Duration>,
+    /// Only known for hosts found with ARP.
+    pub mac: Option<String>,
+}
+
+/// What `HostDiscovery::run` found.
+#[derive(Debug, Clone, Default)]
+pub struct DiscoveryReport {
+    /// Live hosts, in the order they were given.
+    pub live: Vec<LiveHost>,
+    /// Methods that could not be used at all, and why.
+    pub skipped: Vec<(DiscoveryMethod, String)>,
+}
+
+impl DiscoveryReport {
+    pub fn live_ips(&self) -> Vec<IpAddr> {
+        self.live.iter().map(|host| host.ip).collect()
+    }
+}
+
+/// Tells which of a list of hosts are up.
+///
+/// Hosts on a directly attached subnet are asked with ARP first when it is
+/// one of the methods: every host has to answer it, so those that don't
+/// are down and get no other probe. The other hosts get every remaining
+/// method at once and are up as soon as one of them gets an answer.
+///
+/// ICMP and ARP need root or `CAP_NET_RAW` and are skipped without them,
+/// SYN and ACK pings then become TCP connects. IPv6 hosts only get the TCP
+/// and UDP pings.
+#[derive(Debug, Clone)]
+pub struct HostDiscovery {
+    methods: Vec<DiscoveryMethod>,
+    timeout: Duration,
+    tries: u8,
+    concurrency: usize,
+    cancel: Option<CancelToken>,
+    source: Source,
+}
+
+/// A single probe of a host, one port of a method.
+#[derive(Debug, Clone, Copy)]
+enum Probe {
+    Echo,
+    Timestamp,
+    Syn(u16),
+    Ack(u16),
+    Udp(u16),
+}
+
+/// The raw socket senders, shared by every probe of a run.
+#[derive(Default)]
+struct Probers {
+    #[cfg(unix)]
+    syn: Option<SynScanner>,
+    #[cfg(unix)]
+    icmp: Option<IcmpPinger>,
+}
+
+impl HostDiscovery {
+    /// Uses `DiscoveryMethod::defaults()` when `methods` is empty.
+    pub fn new(methods: Vec<DiscoveryMethod>) -> Self {
+        Self {
+            methods: if methods.is_empty() {
+                DiscoveryMethod::defaults()
+            } else {
+                methods
+            },
+            timeout: Duration::from_secs(1),
+            tries: 1,
+            concurrency: 256,
+            cancel: None,
+            source: Source::default(),
+        }
+    }
+
+    /// How long to wait for the answer to a probe.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// How many times an unanswered probe is sent.
+    pub fn with_tries(mut self, tries: u8) -> Self {
+        self.tries = tries.max(1);
+        self
+    }
+
+    /// How many hosts are probed at once.
+    pub fn with_concurrency(mut self, hosts: usize) -> Self {
+        self.concurrency = hosts.max(1);
+        self
+    }
+
+    /// Stops probing new hosts once `token` is cancelled.
+    pub fn with_cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Sends the TCP and UDP pings from `source`, and only sweeps its
+    /// interface with ARP when it names one.
+    pub fn with_source(mut self, source: Source) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub async fn run(&self, ips: &[IpAddr]) -> DiscoveryReport {
+        let mut report = DiscoveryReport::default();
+        let probers = self.probers(&mut report);
+
+        let mut found: HashMap<IpAddr, LiveHost> = HashMap::new();
+        // Hosts whose fate ARP already settled, whether they answered or not.
+        let mut settled: HashSet<IpAddr> = HashSet::new();
+        if self.methods.contains(&DiscoveryMethod::Arp) {
+            self.run_arp(ips, &mut found, &mut settled, &mut report)
+                .await;
+        }
+
+        let rest: Vec<IpAddr> = ips
+            .iter()
+            .copied()
+            .filter(|ip| !settled.contains(ip))
+            .collect();
+        let mut probed = stream::iter(rest)
+            .map(|ip| self.probe_host(ip, &probers))
+            .buffer_unordered(self.concurrency);
+        while let Some(host) = probed.next().await {
+            if let Some(host) = host {
+                found.insert(host.ip, host);
+            }
+        }
+
+        report.live = ips.iter().filter_map(|ip| found.remove(ip)).collect();
+        report
+    }
+
+    #[cfg(unix)]
+    fn probers(&self, report: &mut DiscoveryReport) -> Probers {
+        let wants = |raw: fn(&DiscoveryMethod) -> bool| self.methods.iter().any(raw);
+        let mut probers = Probers::default();
+        if wants(|method| {
+            matches!(
+                method,
+                DiscoveryMethod::TcpSyn(_) | DiscoveryMethod::TcpAck(_)
+            )
+        }) {
+            probers.syn = SynScanner::from_source(&self.source)
+                .map_err(|e| debug!("Host discovery: falling back to TCP connects: {}", e))
+                .ok();
+        }
+        if wants(|method| {
+            matches!(
+                method,
+                DiscoveryMethod::IcmpEcho | DiscoveryMethod::IcmpTimestamp
+            )
+        }) {
+            match IcmpPinger::new() {
+                Ok(icmp) => probers.icmp = Some(icmp),
+                Err(e) => self.skip(report, |method| method != &DiscoveryMethod::Arp, &e),
+            }
+        }
+        probers
+    }
+
+    #[cfg(not(unix))]
+    fn probers(&self, report: &mut DiscoveryReport) -> Probers {
+        let e = io::Error::new(io::ErrorKind::Unsupported, "raw sockets are not supported");
+        self.skip(report, |_| true, &e);
+        Probers::default()
+    }
+
+    /// Records the raw socket methods picked by `which` as skipped.
+    fn skip(
+        &self,
+        report: &mut DiscoveryReport,
+        which: impl Fn(&DiscoveryMethod) -> bool,
+        e: &io::Error,
+    ) {
+        for method in &self.methods {
+            if method.needs_raw_sockets() && which(method) {
+                report.skipped.push((method.clone(), e.to_string()));
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    async fn run_arp(
+        &self,
+        ips: &[IpAddr],
+        found: &mut HashMap<IpAddr, LiveHost>,
+        settled: &mut HashSet<IpAddr>,
+        report: &mut DiscoveryReport,
+    ) {
+        let ipv4: Vec<std::net::Ipv4Addr> = ips
+            .iter()
+            .filter_map(|ip| match ip {
+                IpAddr::V4(ip) => Some(*ip),
+                IpAddr::V6(_) => None,
+            })
+            .collect();
+
+        for (interface, mut targets) in arp::group_by_interface(&ipv4, self.source.interface()) {
+            for _ in 0..self.tries {
+                if targets.is_empty() || self.is_cancelled() {
+                    break;
+                }
+                let (on, sweep, timeout) = (interface.clone(), targets.clone(), self.timeout);
+                let replies =
+                    async_std::task::spawn_blocking(move || arp::arp_sweep(&on, &sweep, timeout))
+                        .await;
+                match replies {
+                    Ok(replies) => {
+                        targets.retain(|ip| !replies.iter().any(|reply| reply.ip == *ip));
+                        for reply in replies {
+                            settled.insert(IpAddr::V4(reply.ip));
+                            found.insert(IpAddr::V4(reply.ip), arp_host(reply));
+                        }
+                    }
+                    Err(e) => {
+                        debug!("Host discovery: ARP on {} failed: {}", interface.name, e);
+                        if !report
+                            .skipped
+                            .iter()
+                            .any(|(method, _)| method == &DiscoveryMethod::Arp)
+                        {
+                            report.skipped.push((DiscoveryMethod::Arp, e.to_string()));
+                        }
+                        // The other methods get to try these hosts.
+                        targets.clear();
+                        break;
+                    }
+                }
+            }
+            // Whatever is left was asked and stayed silent.
+            settled.extend(targets.into_iter().map(IpAddr::V4));
+        }
+    }
+
+    #[cfg(not(unix))]
+    async fn run_arp(
+        &self,
+        _ips: &[IpAddr],
+        _found: &mut HashMap<IpAddr, LiveHost>,
+        _settled: &mut HashSet<IpAddr>,
+        _report: &mut DiscoveryReport,
+    ) {
+    }
+
+    /// Sends every probe to `ip` at once, the first answer wins.
+    async fn probe_host(&self, ip: IpAddr, probers: &Probers) -> Option<LiveHost> {
+        if self.is_cancelled() {
+            return None;
+        }
+        let mut probes: FuturesUnordered<_> = self
+            .probes_for(ip)
+            .into_iter()
+            .map(|probe| self.try_probe(ip, probe, probers))
+            .collect();
+        while let Some(answer) = probes.next().await {
+            if let Some((reason, rtt)) = answer {
+                return Some(LiveHost {
+                    ip,
+                    reason,
+                    rtt: Some(rtt),
+                    mac: None,
+                });
+            }
+        }
+        None
+    }
+
+    fn probes_for(&self, ip: IpAddr) -> Vec<Probe> {
+        let mut probes = Vec::new();
+        for method in &self.methods {
+            match method {
+                DiscoveryMethod::IcmpEcho if ip.is_ipv4() => probes.push(Probe::Echo),
+                DiscoveryMethod::IcmpTimestamp if ip.is_ipv4() => probes.push(Probe::Timestamp),
+                DiscoveryMethod::TcpSyn(ports) => {
+                    probes.extend(ports.iter().map(|p| Probe::Syn(*p)))
+                }
+                DiscoveryMethod::TcpAck(ports) => {
+                    probes.extend(ports.iter().map(|p| Probe::Ack(*p)))
+                }
+                DiscoveryMethod::Udp(ports) => probes.extend(ports.iter().map(|p| Probe::Udp(*p))),
+                _ => {}
+            }
+        }
+        probes
+    }
+
+    /// Sends `probe` up to `tries` times. Returns why the host is up and how
+    /// long it took to tell.
+    async fn try_probe(
+        &self,
+        ip: IpAddr,
+        probe: Probe,
+        probers: &Probers,
+    ) -> Option<(String, Duration)> {
+        for _ in 0..self.tries {
+            let start = Instant::now();
+            match self.send(ip, probe, probers).await {
+                Ok(Some(reason)) => return Some((reason, start.elapsed())),
+                Ok(None) => continue,
+                Err(e) => {
+                    debug!("Host discovery: {:?} to {} failed: {}", probe, ip, e);
+                    return None;
+                }
+            }
+        }
+        None
+    }
+
+    /// Sends `probe` once. `None` means no sign of life.
+    async fn send(
+        &self,
+        ip: IpAddr,
+        probe: Probe,
+        probers: &Probers,
+    ) -> io::Result<Option<String>> {
+        #[cfg(unix)]
+        if let Some(answer) = self.send_raw(ip, probe, probers).await {
+            return answer;
+        }
+        #[cfg(not(unix))]
+        let _ = probers;
+
+        match probe {
+            Probe::Syn(port) | Probe::Ack(port) => {
+                let socket = SocketAddr::new(ip, port);
+                match io::timeout(self.timeout, self.source.connect(socket)).await {
+                    Ok(_) => Ok(Some(format!("syn-ack {port}"))),
+                    Err(e) if e.kind() == io::ErrorKind::ConnectionRefused => {
+                        Ok(Some(format!("reset {port}")))
+                    }
+                    Err(e) if is_silence(&e) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            }
+            Probe::Udp(port) => {
+                let socket = SocketAddr::new(ip, port);
+                let udp_socket = self.source.bind_udp(socket).await?;
+                udp_socket.connect(socket).await?;
+                udp_socket.send(&[]).await?;
+                let mut buf = [0u8; 512];
+                match io::timeout(self.timeout, udp_socket.recv(&mut buf)).await {
+                    Ok(_) => Ok(Some(format!("udp-response {port}"))),
+                    // Port unreachable from the host itself, on a connected
+                    // socket.
+                    Err(e) if e.kind() == io::ErrorKind::ConnectionRefused => {
+                        Ok(Some(format!("port-unreachable {port}")))
+                    }
+                    Err(e) if is_silence(&e) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            }
+            // Skipped, see `HostDiscovery::probers`.
+            Probe::Echo | Probe::Timestamp => Ok(None),
+        }
+    }
+
+    /// Sends `probe` over a raw socket, when there is one for it.
+    #[cfg(unix)]
+    async fn send_raw(
+        &self,
+        ip: IpAddr,
+        probe: Probe,
+        probers: &Probers,
+    ) -> Option<io::Result<Option<String>>> {
+        let IpAddr::V4(ip) = ip else {
+            return None;
+        };
+        let answer = match (probe, &probers.syn, &probers.icmp) {
+            (Probe::Syn(port) | Probe::Ack(port), Some(syn), _) => {
+                let socket = std::net::SocketAddrV4::new(ip, port);
+                let reply = match probe {
+                    Probe::Syn(_) => syn.probe(socket, self.timeout).await,
+                    _ => syn.probe_ack(socket, self.timeout).await,
+                };
+                reply.map(|reply| match reply? {
+                    Reply::SynAck => Some(format!("syn-ack {port}")),
+                    Reply::Reset => Some(format!("reset {port}")),
+                    // Sent by whatever router is in the way, not the host.
+                    Reply::Unreachable => None,
+                })
+            }
+            (Probe::Echo, _, Some(icmp)) => icmp
+                .ping(ip, Ping::Echo, self.timeout)
+                .await
+                .map(|answered| answered.then(|| "echo-reply".to_string())),
+            (Probe::Timestamp, _, Some(icmp)) => icmp
+                .ping(ip, Ping::Timestamp, self.timeout)
+                .await
+                .map(|answered| answered.then(|| "timestamp-reply".to_string())),
+            _ => return None,
+        };
+        Some(answer)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(CancelToken::is_cancelled)
+    }
+}
+
+#[cfg(unix)]
+fn arp_host(reply: ArpReply) -> LiveHost {
+    LiveHost {
+        ip: IpAddr::V4(reply.ip),
+        reason: "arp-response".to_string(),
+        rtt: Some(reply.rtt),
+        mac: Some(reply.mac.to_string().to_uppercase()),
+    }
+}
+
+/// Errors that only mean nothing came back from the host. A local host
+/// that does not answer ARP is unreachable rather than timed out.
+fn is_silence(e: &io::Error) -> bool {
+    matches!(
+        e.kind(),
+        io::ErrorKind::TimedOut
+            | io::ErrorKind::WouldBlock
+            | io::ErrorKind::HostUnreachable
+            | io::ErrorKind::NetworkUnreachable
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiscoveryMethod, HostDiscovery};
+    use async_std::task::block_on;
+    use std::net::{IpAddr, Ipv4Addr, TcpListener};
+    use std::time::Duration;
+
+    #[test]
+    fn methods_parse_and_print() {
+        let cases = [
+            ("echo", DiscoveryMethod::IcmpEcho),
+            ("timestamp", DiscoveryMethod::IcmpTimestamp),
+            ("ARP", DiscoveryMethod::Arp),
+            ("syn", DiscoveryMethod::TcpSyn(vec![80])),
+            ("ack:22,443", DiscoveryMethod::TcpAck(vec![22, 443])),
+            ("udp", DiscoveryMethod::Udp(vec![40125])),
+        ];
+        for (text, method) in cases {
+            assert_eq!(text.parse::<DiscoveryMethod>(), Ok(method.clone()));
+            assert_eq!(method.to_string().parse(), Ok(method));
+        }
+        assert!("syn:http".parse::<DiscoveryMethod>().is_err());
+        assert!("echo:80".parse::<DiscoveryMethod>().is_err());
+        assert!("smoke".parse::<DiscoveryMethod>().is_err());
+    }
+
+    #[test]
+    fn tcp_ping_finds_loopback() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let open = listener.local_addr().unwrap().port();
+        let closed = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        for port in [open, closed] {
+            let discovery = HostDiscovery::new(vec![DiscoveryMethod::TcpSyn(vec![port])])
+                .with_timeout(Duration::from_millis(500));
+            let report = block_on(discovery.run(&[IpAddr::V4(Ipv4Addr::LOCALHOST)]));
+            assert_eq!(report.live_ips(), [IpAddr::V4(Ipv4Addr::LOCALHOST)]);
+            assert!(report.skipped.is_empty());
+        }
+    }
+}
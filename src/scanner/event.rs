@@ -0,0 +1,237 @@
+//! Structured events emitted while a scan is running.
+//!
+//! Frontends consume these through [`Scanner::events`](super::Scanner::events)
+//! instead of guessing the state of the scan from status strings.
+use super::{PortState, ScanResult};
+use std::fmt;
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+/// A single event in the life of a scan.
+#[derive(Debug, Clone)]
+pub enum ScanEvent {
+    /// The first socket of this host has been queued.
+    HostStarted {
+        ip: IpAddr,
+    },
+    /// A socket has been scanned and classified.
+    PortResult(ScanResult),
+    /// The sockets of this host that were not probed yet will not be.
+    HostSkipped {
+        ip: IpAddr,
+        reason: SkipReason,
+    },
+    /// Every socket of this host has been scanned. `srtt` is the smoothed
+    /// round-trip time of the host, if it ever answered.
+    HostFinished {
+        ip: IpAddr,
+        open_ports: usize,
+        srtt: Option<Duration>,
+    },
+    Progress(ScanProgress),
+    /// A socket could not be scanned because of a local problem.
+    Error {
+        socket: SocketAddr,
+        kind: ScanErrorKind,
+        message: String,
+    },
+    /// The scan is over, this is always the last event.
+    Finished(ScanSummary),
+}
+
+/// How far along the scan is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScanProgress {
+    pub completed: usize,
+    pub total: usize,
+    pub elapsed: Duration,
+    pub eta: Option<Duration>,
+}
+
+impl ScanProgress {
+    pub fn new(completed: usize, total: usize, elapsed: Duration) -> Self {
+        let eta = if completed == 0 || completed >= total {
+            None
+        } else {
+            Some(elapsed.mul_f64((total - completed) as f64 / completed as f64))
+        };
+
+        Self {
+            completed,
+            total,
+            elapsed,
+            eta,
+        }
+    }
+
+    /// Completed share of the scan, between 0.0 and 1.0.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.completed as f32 / self.total as f32
+        }
+    }
+}
+
+/// Classification of errors that say nothing about the target itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanErrorKind {
+    /// We ran out of file descriptors or ephemeral ports.
+    ResourceExhausted,
+    /// The OS refused to send the probe, e.g. a broadcast address.
+    InvalidTarget,
+    Other,
+}
+
+impl ScanErrorKind {
+    pub fn from_error(error: &io::Error) -> Self {
+        if error
+            .to_string()
+            .to_lowercase()
+            .contains("too many open files")
+        {
+            return ScanErrorKind::ResourceExhausted;
+        }
+
+        match error.kind() {
+            io::ErrorKind::AddrInUse
+            | io::ErrorKind::AddrNotAvailable
+            | io::ErrorKind::OutOfMemory => ScanErrorKind::ResourceExhausted,
+            io::ErrorKind::InvalidInput | io::ErrorKind::Unsupported => {
+                ScanErrorKind::InvalidTarget
+            }
+            _ => ScanErrorKind::Other,
+        }
+    }
+}
+
+/// Why a scan ended before every socket was scanned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Cancelled,
+    DeadlineExceeded,
+}
+
+/// Why the remaining sockets of a host were left out of the scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The host ran out of time, see `ScannerBuilder::with_host_deadline`.
+    DeadlineExceeded,
+    /// The first `probes` sockets of the host all went unanswered, see
+    /// `ScannerBuilder::with_dead_host_threshold`.
+    Unresponsive { probes: usize },
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkipReason::DeadlineExceeded => f.write_str("ran out of time"),
+            SkipReason::Unresponsive { probes } => {
+                write!(
+                    f,
+                    "looks down, none of its first {probes} probes got an answer"
+                )
+            }
+        }
+    }
+}
+
+/// Totals for a whole scan.
+///
+/// `unscanned` counts the sockets that were never probed, either because the
+/// scan was stopped (see `stopped`) or because their host was skipped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScanSummary {
+    pub hosts: usize,
+    pub sockets: usize,
+    pub open: usize,
+    pub closed: usize,
+    pub filtered: usize,
+    pub open_filtered: usize,
+    pub unreachable: usize,
+    pub errors: usize,
+    pub unscanned: usize,
+    pub stopped: Option<StopReason>,
+    pub duration: Duration,
+}
+
+impl ScanSummary {
+    pub fn record(&mut self, result: &ScanResult) {
+        self.sockets += 1;
+        match result.state {
+            PortState::Open => self.open += 1,
+            PortState::Closed => self.closed += 1,
+            PortState::Filtered => self.filtered += 1,
+            PortState::OpenFiltered => self.open_filtered += 1,
+            PortState::Unreachable => self.unreachable += 1,
+        }
+    }
+
+    pub fn record_error(&mut self) {
+        self.sockets += 1;
+        self.errors += 1;
+    }
+
+    /// Whether every socket of the scan was probed.
+    pub fn is_complete(&self) -> bool {
+        self.unscanned == 0 && self.stopped.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScanErrorKind, ScanProgress, ScanSummary};
+    use crate::scanner::{PortState, ScanResult};
+    use std::io;
+    use std::time::Duration;
+
+    #[test]
+    fn eta_scales_with_remaining_work() {
+        let progress = ScanProgress::new(25, 100, Duration::from_secs(10));
+        assert_eq!(progress.eta, Some(Duration::from_secs(30)));
+        assert!((progress.fraction() - 0.25).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn no_eta_before_first_result_or_after_last() {
+        assert_eq!(ScanProgress::new(0, 100, Duration::from_secs(1)).eta, None);
+        assert_eq!(
+            ScanProgress::new(100, 100, Duration::from_secs(1)).eta,
+            None
+        );
+    }
+
+    #[test]
+    fn summary_counts_states() {
+        let socket = "127.0.0.1:80".parse().unwrap();
+        let mut summary = ScanSummary::default();
+        summary.record(&ScanResult::new(socket, PortState::Open));
+        summary.record(&ScanResult::new(socket, PortState::Filtered));
+        summary.record_error();
+
+        assert_eq!(summary.sockets, 3);
+        assert_eq!(summary.open, 1);
+        assert_eq!(summary.filtered, 1);
+        assert_eq!(summary.errors, 1);
+    }
+
+    #[test]
+    fn emfile_is_resource_exhaustion() {
+        let error = io::Error::other("Too many open files (os error 24)");
+        assert_eq!(
+            ScanErrorKind::from_error(&error),
+            ScanErrorKind::ResourceExhausted
+        );
+    }
+
+    #[test]
+    fn no_ephemeral_port_left_is_resource_exhaustion() {
+        let error = io::Error::from(io::ErrorKind::AddrNotAvailable);
+        assert_eq!(
+            ScanErrorKind::from_error(&error),
+            ScanErrorKind::ResourceExhausted
+        );
+    }
+}
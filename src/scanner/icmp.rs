@@ -0,0 +1,205 @@
+//! ICMP echo and timestamp pings over a raw socket.
+//!
+//! Needs root or `CAP_NET_RAW`, like the SYN scanner. Only IPv4 hosts are
+//! pinged.
+use chrono::Timelike;
+use futures::channel::oneshot;
+use log::debug;
+use pnet_packet::icmp::{self, IcmpPacket, IcmpType, IcmpTypes};
+use pnet_packet::ip::IpNextHeaderProtocols;
+use pnet_packet::Packet;
+use pnet_transport::TransportChannelType::Layer4;
+use pnet_transport::TransportProtocol::Ipv4;
+use pnet_transport::{icmp_packet_iter, transport_channel, TransportReceiver, TransportSender};
+use std::collections::HashMap;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::Duration;
+
+const CHANNEL_BUFFER_SIZE: usize = 1 << 16;
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Echo requests carry 8 bytes of data, like the ones ping sends.
+const ECHO_LENGTH: usize = 16;
+/// Type, code, checksum, identifier, sequence and three timestamps.
+const TIMESTAMP_LENGTH: usize = 20;
+
+/// The two kinds of ICMP ping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Ping {
+    Echo,
+    Timestamp,
+}
+
+impl Ping {
+    fn request_type(self) -> IcmpType {
+        match self {
+            Ping::Echo => IcmpTypes::EchoRequest,
+            Ping::Timestamp => IcmpTypes::Timestamp,
+        }
+    }
+
+    fn from_reply_type(reply: IcmpType) -> Option<Self> {
+        match reply {
+            IcmpTypes::EchoReply => Some(Ping::Echo),
+            IcmpTypes::TimestampReply => Some(Ping::Timestamp),
+            _ => None,
+        }
+    }
+}
+
+/// Sends pings and matches the replies to them. Clones share the socket.
+#[derive(Clone)]
+pub(crate) struct IcmpPinger {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    sender: Mutex<TransportSender>,
+    waiting: Mutex<HashMap<(Ipv4Addr, Ping), oneshot::Sender<()>>>,
+    identifier: u16,
+}
+
+impl IcmpPinger {
+    /// Fails with `PermissionDenied` without raw socket privileges.
+    pub(crate) fn new() -> io::Result<Self> {
+        let (sender, receiver) = transport_channel(
+            CHANNEL_BUFFER_SIZE,
+            Layer4(Ipv4(IpNextHeaderProtocols::Icmp)),
+        )?;
+        let shared = Arc::new(Shared {
+            sender: Mutex::new(sender),
+            waiting: Mutex::new(HashMap::new()),
+            identifier: rand::random(),
+        });
+        let weak = Arc::downgrade(&shared);
+        thread::Builder::new()
+            .name("icmp-ping".into())
+            .spawn(move || receive(receiver, &weak))?;
+        Ok(Self { shared })
+    }
+
+    /// Pings `ip` and tells whether it answered within `timeout`.
+    pub(crate) async fn ping(
+        &self,
+        ip: Ipv4Addr,
+        ping: Ping,
+        timeout: Duration,
+    ) -> io::Result<bool> {
+        let (tx, rx) = oneshot::channel();
+        self.shared.waiting.lock().unwrap().insert((ip, ping), tx);
+
+        let request = request_packet(ping, self.shared.identifier, rand::random());
+        let sent = self
+            .shared
+            .sender
+            .lock()
+            .unwrap()
+            .send_to(IcmpPacket::new(&request).unwrap(), IpAddr::V4(ip));
+        if let Err(e) = sent {
+            self.shared.waiting.lock().unwrap().remove(&(ip, ping));
+            return Err(e);
+        }
+
+        let answered = async_std::future::timeout(timeout, rx).await.is_ok();
+        if !answered {
+            self.shared.waiting.lock().unwrap().remove(&(ip, ping));
+        }
+        Ok(answered)
+    }
+}
+
+fn receive(mut receiver: TransportReceiver, shared: &Weak<Shared>) {
+    let mut packets = icmp_packet_iter(&mut receiver);
+    while let Some(shared) = shared.upgrade() {
+        match packets.next_with_timeout(POLL_INTERVAL) {
+            Ok(Some((packet, IpAddr::V4(from)))) => {
+                if let Some(ping) = parse_reply(&packet, shared.identifier) {
+                    if let Some(waiter) = shared.waiting.lock().unwrap().remove(&(from, ping)) {
+                        let _ = waiter.send(());
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(e) => debug!("ICMP ping: receiving failed: {}", e),
+        }
+    }
+}
+
+/// Builds an echo or timestamp request. Identifier and sequence sit at the
+/// same place in both.
+fn request_packet(ping: Ping, identifier: u16, sequence: u16) -> Vec<u8> {
+    let length = match ping {
+        Ping::Echo => ECHO_LENGTH,
+        Ping::Timestamp => TIMESTAMP_LENGTH,
+    };
+    let mut buffer = vec![0; length];
+    buffer[0] = ping.request_type().0;
+    buffer[4..6].copy_from_slice(&identifier.to_be_bytes());
+    buffer[6..8].copy_from_slice(&sequence.to_be_bytes());
+    if ping == Ping::Timestamp {
+        // Originate timestamp, in milliseconds since midnight UTC.
+        let now = chrono::Utc::now().time();
+        let millis = now.num_seconds_from_midnight() * 1000 + now.nanosecond() / 1_000_000;
+        buffer[8..12].copy_from_slice(&millis.to_be_bytes());
+    }
+    let checksum = icmp::checksum(&IcmpPacket::new(&buffer).unwrap());
+    buffer[2..4].copy_from_slice(&checksum.to_be_bytes());
+    buffer
+}
+
+/// Tells which of our pings `packet` answers. The raw socket sees every
+/// ICMP packet of the host, including our own requests on loopback.
+fn parse_reply(packet: &IcmpPacket, identifier: u16) -> Option<Ping> {
+    let ping = Ping::from_reply_type(packet.get_icmp_type())?;
+    // The payload starts right after type, code and checksum.
+    let rest = packet.payload();
+    (rest.len() >= 4 && u16::from_be_bytes([rest[0], rest[1]]) == identifier).then_some(ping)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_are_well_formed() {
+        for (ping, length) in [
+            (Ping::Echo, ECHO_LENGTH),
+            (Ping::Timestamp, TIMESTAMP_LENGTH),
+        ] {
+            let buffer = request_packet(ping, 0xbeef, 7);
+            let packet = IcmpPacket::new(&buffer).unwrap();
+            assert_eq!(buffer.len(), length);
+            assert_eq!(packet.get_icmp_type(), ping.request_type());
+            assert_eq!(packet.get_checksum(), icmp::checksum(&packet));
+            assert_eq!(&buffer[4..8], [0xbe, 0xef, 0, 7]);
+        }
+    }
+
+    #[test]
+    fn only_replies_to_our_pings_match() {
+        let mut reply = request_packet(Ping::Timestamp, 0xbeef, 7);
+        assert_eq!(parse_reply(&IcmpPacket::new(&reply).unwrap(), 0xbeef), None);
+
+        reply[0] = IcmpTypes::TimestampReply.0;
+        let packet = IcmpPacket::new(&reply).unwrap();
+        assert_eq!(parse_reply(&packet, 0xbeef), Some(Ping::Timestamp));
+        assert_eq!(parse_reply(&packet, 0xcafe), None);
+    }
+
+    #[test]
+    fn loopback_answers_pings() {
+        // Raw sockets need privileges the test runner may not have.
+        let Ok(pinger) = IcmpPinger::new() else {
+            return;
+        };
+        let answered = futures::executor::block_on(pinger.ping(
+            Ipv4Addr::LOCALHOST,
+            Ping::Echo,
+            Duration::from_secs(1),
+        ))
+        .unwrap();
+        assert!(answered);
+    }
+}
@@ -0,0 +1,239 @@
+//! Limits that keep fragile targets from being hit by bursts.
+//!
+//! `RateLimiter` spaces probes evenly in time, `ConcurrencyCaps` bounds how
+//! many probes a single host, or a single subnet, has in flight at once.
+use std::collections::{HashMap, VecDeque};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Hands out evenly spaced time slots, one per probe.
+///
+/// There is deliberately no burst allowance: an idle period does not let the
+/// next probes go out back to back.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next_slot: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// `rate` is in probes per second.
+    pub(crate) fn new(rate: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / rate.max(1),
+            next_slot: None,
+        }
+    }
+
+    /// Reserves the next free slot, which is never earlier than `now`.
+    pub(crate) fn reserve(&mut self, now: Instant) -> Instant {
+        let slot = self.next_slot.map_or(now, |next| next.max(now));
+        self.next_slot = Some(slot + self.interval);
+        slot
+    }
+}
+
+/// Waits until the limiter allows one more probe to go out.
+pub(crate) async fn pace(limiter: Option<&Mutex<RateLimiter>>) {
+    let Some(limiter) = limiter else {
+        return;
+    };
+    let now = Instant::now();
+    let slot = limiter.lock().unwrap().reserve(now);
+    if slot > now {
+        async_std::task::sleep(slot - now).await;
+    }
+}
+
+/// Per-host and per-subnet bounds on the number of probes in flight.
+/// Subnets are IPv4 /24s and IPv6 /64s.
+#[derive(Debug, Default)]
+pub(crate) struct ConcurrencyCaps {
+    per_host: Option<usize>,
+    per_subnet: Option<usize>,
+    hosts: HashMap<IpAddr, usize>,
+    subnets: HashMap<IpAddr, usize>,
+}
+
+impl ConcurrencyCaps {
+    pub(crate) fn new(per_host: Option<usize>, per_subnet: Option<usize>) -> Self {
+        Self {
+            per_host: per_host.map(|limit| limit.max(1)),
+            per_subnet: per_subnet.map(|limit| limit.max(1)),
+            ..Self::default()
+        }
+    }
+
+    /// Whether one more probe to `ip` would stay within the caps.
+    pub(crate) fn admits(&self, ip: IpAddr) -> bool {
+        let below = |limit: Option<usize>, counts: &HashMap<IpAddr, usize>, key: IpAddr| {
+            limit.is_none_or(|limit| counts.get(&key).copied().unwrap_or(0) < limit)
+        };
+        below(self.per_host, &self.hosts, ip)
+            && below(self.per_subnet, &self.subnets, subnet_of(ip))
+    }
+
+    pub(crate) fn acquire(&mut self, ip: IpAddr) {
+        if self.per_host.is_some() {
+            *self.hosts.entry(ip).or_default() += 1;
+        }
+        if self.per_subnet.is_some() {
+            *self.subnets.entry(subnet_of(ip)).or_default() += 1;
+        }
+    }
+
+    pub(crate) fn release(&mut self, ip: IpAddr) {
+        fn decrement(counts: &mut HashMap<IpAddr, usize>, key: IpAddr) {
+            if let Some(count) = counts.get_mut(&key) {
+                *count -= 1;
+                if *count == 0 {
+                    counts.remove(&key);
+                }
+            }
+        }
+        decrement(&mut self.hosts, ip);
+        decrement(&mut self.subnets, subnet_of(ip));
+    }
+}
+
+/// Sockets waiting for their host or subnet to drop below its cap.
+///
+/// They are grouped by host so that finding one that may go out only means
+/// looking at each waiting host once, not at every waiting socket.
+#[derive(Debug, Default)]
+pub(crate) struct PendingSockets {
+    sockets: HashMap<IpAddr, VecDeque<SocketAddr>>,
+    hosts: VecDeque<IpAddr>,
+    len: usize,
+}
+
+impl PendingSockets {
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn push_back(&mut self, socket: SocketAddr) {
+        self.queue_of(socket.ip()).push_back(socket);
+        self.len += 1;
+    }
+
+    /// Puts `socket` first in line among the sockets of its host.
+    pub(crate) fn push_front(&mut self, socket: SocketAddr) {
+        self.queue_of(socket.ip()).push_front(socket);
+        self.len += 1;
+    }
+
+    /// Takes the oldest socket whose host the caps currently admit.
+    pub(crate) fn take(&mut self, caps: &ConcurrencyCaps) -> Option<SocketAddr> {
+        let index = self.hosts.iter().position(|ip| caps.admits(*ip))?;
+        let ip = self.hosts[index];
+        let queue = self.sockets.get_mut(&ip)?;
+        let socket = queue.pop_front();
+        if queue.is_empty() {
+            self.sockets.remove(&ip);
+            self.hosts.remove(index);
+        }
+        self.len -= 1;
+        socket
+    }
+
+    fn queue_of(&mut self, ip: IpAddr) -> &mut VecDeque<SocketAddr> {
+        if !self.sockets.contains_key(&ip) {
+            self.hosts.push_back(ip);
+        }
+        self.sockets.entry(ip).or_default()
+    }
+}
+
+/// The network address of the /24 (IPv4) or /64 (IPv6) `ip` belongs to.
+fn subnet_of(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V4(ip) => IpAddr::V4(Ipv4Addr::from(u32::from(ip) & 0xffff_ff00)),
+        IpAddr::V6(ip) => IpAddr::V6(Ipv6Addr::from(u128::from(ip) & !(u128::from(u64::MAX)))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{subnet_of, ConcurrencyCaps, PendingSockets, RateLimiter};
+    use std::net::{IpAddr, SocketAddr};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn slots_are_evenly_spaced() {
+        let mut limiter = RateLimiter::new(10);
+        let now = Instant::now();
+
+        assert_eq!(limiter.reserve(now), now);
+        assert_eq!(limiter.reserve(now), now + Duration::from_millis(100));
+        assert_eq!(limiter.reserve(now), now + Duration::from_millis(200));
+    }
+
+    #[test]
+    fn idle_time_does_not_allow_a_burst() {
+        let mut limiter = RateLimiter::new(10);
+        let now = Instant::now();
+        limiter.reserve(now);
+
+        let later = now + Duration::from_secs(5);
+        assert_eq!(limiter.reserve(later), later);
+        assert_eq!(limiter.reserve(later), later + Duration::from_millis(100));
+    }
+
+    #[test]
+    fn host_cap() {
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+        let mut caps = ConcurrencyCaps::new(Some(2), None);
+
+        caps.acquire(ip);
+        assert!(caps.admits(ip));
+        caps.acquire(ip);
+        assert!(!caps.admits(ip));
+        assert!(caps.admits("10.0.0.2".parse().unwrap()));
+
+        caps.release(ip);
+        assert!(caps.admits(ip));
+    }
+
+    #[test]
+    fn subnet_cap_spans_hosts() {
+        let mut caps = ConcurrencyCaps::new(None, Some(1));
+        caps.acquire("10.0.0.1".parse().unwrap());
+
+        assert!(!caps.admits("10.0.0.2".parse().unwrap()));
+        assert!(caps.admits("10.0.1.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn pending_sockets_skip_capped_hosts() {
+        let busy: SocketAddr = "10.0.0.1:80".parse().unwrap();
+        let idle: SocketAddr = "10.0.0.2:80".parse().unwrap();
+        let mut caps = ConcurrencyCaps::new(Some(1), None);
+        caps.acquire(busy.ip());
+
+        let mut pending = PendingSockets::default();
+        pending.push_back(busy);
+        pending.push_back(idle);
+
+        assert_eq!(pending.take(&caps), Some(idle));
+        assert_eq!(pending.take(&caps), None);
+        assert_eq!(pending.len(), 1);
+
+        caps.release(busy.ip());
+        assert_eq!(pending.take(&caps), Some(busy));
+        assert_eq!(pending.len(), 0);
+    }
+
+    #[test]
+    fn subnets_are_slash_24_and_slash_64() {
+        assert_eq!(
+            subnet_of("192.168.7.42".parse().unwrap()),
+            "192.168.7.0".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(
+            subnet_of("2001:db8:1:2:3:4:5:6".parse().unwrap()),
+            "2001:db8:1:2::".parse::<IpAddr>().unwrap()
+        );
+    }
+}
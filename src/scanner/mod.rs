@@ -1,80 +1,182 @@
 //! Core functionality for actual scanning behaviour.
-use crate::generated::get_parsed_data;
 use crate::port_strategy::PortStrategy;
 use log::debug;
 
-mod socket_iterator;
+#[cfg(unix)]
+mod arp;
+mod banner;
+mod builder;
+mod cancel;
+mod checkpoint;
+mod congestion;
+mod discovery;
+mod event;
 pub mod external;
+#[cfg(unix)]
+mod icmp;
+mod limits;
+mod payloads;
+mod permutation;
+mod proxy;
+mod result;
+mod rtt;
+mod shard;
+mod simulated;
+mod socket_iterator;
+mod source;
+#[cfg(unix)]
+mod syn;
+mod transport;
+#[cfg(unix)]
+pub use arp::{arp_sweep, group_by_interface, ArpReply};
+pub(crate) use banner::read_banner;
+pub use banner::{grab_banner, Banner, BannerGrab, HTTP_PORTS, TLS_PORTS};
+pub use builder::ScannerBuilder;
+pub use cancel::CancelToken;
+pub use checkpoint::Checkpoint;
+use checkpoint::Progress;
+use congestion::{CongestionWindow, Signal};
+pub use discovery::{DiscoveryMethod, DiscoveryReport, HostDiscovery, LiveHost};
+pub use event::{ScanErrorKind, ScanEvent, ScanProgress, ScanSummary, SkipReason, StopReason};
+use limits::{pace, ConcurrencyCaps, PendingSockets, RateLimiter};
+pub use payloads::{UdpPayload, UdpPayloads};
+pub use proxy::{Proxy, ProxyKind, ProxyTransport};
+pub use result::{PortState, ScanReport, ScanResult};
+use rtt::HostRtts;
+pub use rtt::RttEstimator;
+pub use shard::Shard;
+pub use simulated::{SimulatedNetwork, SimulatedProbe, SimulatedSocket};
 use socket_iterator::SocketIterator;
+pub use source::Source;
+#[cfg(unix)]
+use syn::Reply;
+#[cfg(unix)]
+pub use syn::SynScanner;
+pub use transport::{IcmpEvent, OsTransport, Transport, UdpReply};
 
-use async_std::net::TcpStream;
+use async_channel::Sender;
+use async_std::io;
 use async_std::prelude::*;
-use async_std::{io, net::UdpSocket};
-use colored::Colorize;
+use futures::future::{self, Either};
 use futures::stream::FuturesUnordered;
-use std::collections::BTreeMap;
 use std::{
-    collections::HashSet,
-    net::{IpAddr, Shutdown, SocketAddr},
+    collections::{HashMap, HashSet},
+    net::{IpAddr, SocketAddr},
     num::NonZeroU8,
-    time::Duration,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
-/// The class for the scanner
+/// How many events may pile up before the scan waits for the consumer.
+const EVENT_BUFFER_SIZE: usize = 1024;
+/// How many sockets may wait for their host or subnet to drop below its
+/// concurrency cap before the scan stops pulling new ones.
+const DEFERRED_LIMIT: usize = 4096;
+/// Minimum time between two `ScanEvent::Progress` events.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+/// Minimum time between two checkpoints written to disk.
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The class for the scanner, set up through `ScannerBuilder`. The
+/// `with_` methods named below are those of the builder.
 /// IP is data type IpAddr and is the IP address
 /// start & end is where the port scan starts and ends
 /// batch_size is how many ports at a time should be scanned
 /// Timeout is the time RustScan should wait before declaring a port closed. As datatype Duration.
-/// greppable is whether or not RustScan should print things, or wait until the end to print only the ip and open ports.
+/// cancel, deadline and host_deadline allow stopping a scan early, see `with_cancel_token`.
+/// dead_host_threshold and live_hosts skip hosts that look down, see `with_dead_host_threshold`.
+/// adaptive lets the scanner tune the batch size on the fly, see `with_adaptive`.
+/// max_rate, max_per_host and max_per_subnet protect fragile targets from bursts.
+/// timeout_bounds turns on per-host timeouts derived from measured RTTs, see `with_dynamic_timeout`.
+/// banner_grab reads what open TCP services say after the connect, see `with_banner_grab`.
+/// syn sends half-open probes over raw sockets instead of connecting, see `with_syn`.
+/// udp_payloads are the datagrams sent to UDP ports, see `with_udp_payloads`.
+/// checkpoint and resume save the progress to disk and pick it up again, see `with_checkpoint`.
+/// shard limits the scan to its part of the sockets, see `with_shard`.
+/// socket_seed shuffles every IP and port pair together, see `with_shuffled_sockets`.
+/// all_results keeps closed, filtered and unreachable sockets in the report, see `with_all_results`.
+/// transport carries the probes, the OS network from `with_source` unless `with_transport` says otherwise.
 #[cfg(not(tarpaulin_include))]
 #[derive(Debug)]
-pub struct Scanner {
+pub struct Scanner<T = OsTransport> {
     ips: Vec<IpAddr>,
     batch_size: u16,
     timeout: Duration,
     tries: NonZeroU8,
-    greppable: bool,
     port_strategy: PortStrategy,
-    accessible: bool,
     exclude_ports: Vec<u16>,
     udp: bool,
+    cancel: Option<CancelToken>,
+    deadline: Option<Duration>,
+    host_deadline: Option<Duration>,
+    dead_host_threshold: Option<usize>,
+    live_hosts: HashSet<IpAddr>,
+    adaptive: bool,
+    max_rate: Option<u32>,
+    max_per_host: Option<usize>,
+    max_per_subnet: Option<usize>,
+    timeout_bounds: Option<(Duration, Duration)>,
+    banner_grab: Option<BannerGrab>,
+    #[cfg(unix)]
+    syn: Option<SynScanner>,
+    udp_payloads: Option<UdpPayloads>,
+    checkpoint: Option<PathBuf>,
+    resume: Option<Checkpoint>,
+    shard: Option<Shard>,
+    socket_seed: Option<u64>,
+    all_results: bool,
+    transport: T,
 }
 
-// Allowing too many arguments for clippy.
-#[allow(clippy::too_many_arguments)]
 impl Scanner {
-    pub fn new(
-        ips: &[IpAddr],
-        batch_size: u16,
-        timeout: Duration,
-        tries: u8,
-        greppable: bool,
-        port_strategy: PortStrategy,
-        accessible: bool,
-        exclude_ports: Vec<u16>,
-        udp: bool,
-    ) -> Self {
-        Self {
-            batch_size,
-            timeout,
-            tries: NonZeroU8::new(std::cmp::max(tries, 1)).unwrap(),
-            greppable,
-            port_strategy,
-            ips: ips.iter().map(ToOwned::to_owned).collect(),
-            accessible,
-            exclude_ports,
-            udp,
-        }
+    /// Starts setting up a scan of `ips`, see `ScannerBuilder`.
+    pub fn builder(ips: &[IpAddr]) -> ScannerBuilder {
+        ScannerBuilder::new(ips)
     }
+}
 
+impl<T: Transport> Scanner<T> {
     /// Runs scan_range with chunk sizes
     /// If you want to run RustScan normally, this is the entry point used
-    /// Returns all open ports as `Vec<SocketAddr>`
-    pub async fn run(
-        &self,
-        on_progress: Option<std::sync::Arc<dyn Fn(f32) + Send + Sync>>,
-        on_result: Option<std::sync::Arc<dyn Fn(SocketAddr) + Send + Sync>>,
-    ) -> Vec<SocketAddr> {
+    /// Returns the state of every scanned socket wrapped in a `ScanReport`,
+    /// which tells whether the scan ran to completion.
+    pub async fn run(&self) -> ScanReport {
+        self.scan(None).await
+    }
+
+    /// Runs the scan like `run`, handing every `ScanEvent` to `on_event`
+    /// as it happens, e.g. to show open ports before the scan is over.
+    pub async fn run_with(&self, on_event: impl FnMut(ScanEvent)) -> ScanReport {
+        let (sender, receiver) = async_channel::bounded(EVENT_BUFFER_SIZE);
+        let scan = async {
+            let report = self.scan(Some(&sender)).await;
+            sender.close();
+            report
+        };
+        let (report, ()) = future::join(scan, receiver.for_each(on_event)).await;
+        report
+    }
+
+    /// Returns a stream of `ScanEvent`s describing the scan as it happens.
+    /// The stream ends right after `ScanEvent::Finished`.
+    ///
+    /// Nothing is spawned: the scan moves forward while the stream is
+    /// polled, from whichever runtime polls it, and stops when the stream
+    /// is dropped.
+    pub fn events(self) -> impl Stream<Item = ScanEvent> + Send
+    where
+        T: 'static,
+    {
+        let (sender, receiver) = async_channel::bounded(EVENT_BUFFER_SIZE);
+        let scan = futures::stream::once(async move {
+            self.scan(Some(&sender)).await;
+            None
+        });
+        futures::stream::select(receiver.map(Some), scan).filter_map(|event| event)
+    }
+
+    async fn scan(&self, events: Option<&Sender<ScanEvent>>) -> ScanReport {
         let ports: Vec<u16> = self
             .port_strategy
             .order()
@@ -82,74 +184,441 @@ impl Scanner {
             .filter(|&port| !self.exclude_ports.contains(port))
             .copied()
             .collect();
-        let mut socket_iterator: SocketIterator = SocketIterator::new(&self.ips, &ports);
-        let mut open_sockets: Vec<SocketAddr> = Vec::new();
+        let shard = self.shard.unwrap_or_default();
+        let position = self
+            .resume
+            .as_ref()
+            .map_or(0, |checkpoint| checkpoint.position);
+        let mut socket_iterator: SocketIterator =
+            SocketIterator::starting_at(&self.ips, &ports, position).sharded(shard);
+        if let Some(seed) = self.socket_seed {
+            socket_iterator = socket_iterator.shuffled(seed);
+        }
+        let progress = self
+            .resume
+            .as_ref()
+            .map_or_else(Progress::default, Progress::resume);
+        let mut results: Vec<ScanResult> = Vec::new();
+        let probes = Probes {
+            limiter: self.max_rate.map(|rate| Mutex::new(RateLimiter::new(rate))),
+            ..Probes::default()
+        };
         let mut ftrs = FuturesUnordered::new();
         let mut errors: HashSet<String> = HashSet::new();
-        let udp_map = get_parsed_data();
-
-        let total_scans = (self.ips.len() * ports.len()) as f32;
-        let mut finished_scans = 0.0;
 
-        for _ in 0..self.batch_size {
-            if let Some(socket) = socket_iterator.next() {
-                ftrs.push(self.scan_socket(socket, udp_map));
-            } else {
-                break;
+        let total_scans = SocketIterator::starting_at(&self.ips, &ports, 0)
+            .sharded(shard)
+            .remaining();
+        let resumed = total_scans - socket_iterator.remaining();
+        let finished_scans: usize = resumed;
+        let mut summary = ScanSummary::default();
+        let mut hosts: HashMap<IpAddr, HostTally> = HashMap::new();
+        let mut counted = match socket_iterator.remaining_by_ip() {
+            Some(by_ip) => {
+                for (ip, remaining) in self.ips.iter().zip(by_ip) {
+                    let tally = hosts.entry(*ip).or_default();
+                    tally.remaining += remaining;
+                    tally.counted = true;
+                }
+                true
+            }
+            // Counted as they come up instead, see `HostTally::counted`.
+            None => {
+                for ip in &self.ips {
+                    hosts.entry(*ip).or_default();
+                }
+                false
+            }
+        };
+        summary.hosts = hosts.len();
+        for result in self
+            .resume
+            .iter()
+            .flat_map(|checkpoint| &checkpoint.results)
+        {
+            summary.record(result);
+            if result.state.is_open() {
+                let tally = hosts.entry(result.socket.ip()).or_default();
+                tally.open += 1;
+                tally.answered = true;
             }
+            results.push(result.clone());
         }
 
+        let mut state = ScanState {
+            sockets: socket_iterator,
+            progress,
+            hosts,
+            summary,
+            finished_scans,
+        };
+
+        let start = Instant::now();
+        let mut last_progress = start;
+        let mut last_checkpoint = start;
+        let mut stop_signal = Box::pin(self.stop_signal());
+
+        let mut window = if self.adaptive {
+            CongestionWindow::adaptive(self.batch_size.into())
+        } else {
+            CongestionWindow::fixed(self.batch_size.into())
+        };
+        let mut caps = ConcurrencyCaps::new(self.max_per_host, self.max_per_subnet);
+        // Sockets held back by the caps, or that could not be opened because
+        // we ran out of descriptors.
+        let mut pending = PendingSockets::default();
+
         debug!("Start scanning sockets. \nBatch size {}\nNumber of ip-s {}\nNumber of ports {}\nTargets all together {} ",
             self.batch_size,
             self.ips.len(),
             &ports.len(),
             (self.ips.len() * ports.len()));
 
-        while let Some(result) = ftrs.next().await {
-            if let Some(socket) = socket_iterator.next() {
-                ftrs.push(self.scan_socket(socket, udp_map));
+        loop {
+            if let Some(reason) = self.stop_reason(start) {
+                state.summary.stopped = Some(reason);
+                break;
             }
 
-            match result {
-                Ok(socket) => {
-                    open_sockets.push(socket);
-                    if let Some(ref result_cb) = on_result {
-                        result_cb(socket);
+            let in_flight = self.transport.max_in_flight().unwrap_or(usize::MAX);
+            while ftrs.len() < window.limit().min(in_flight) {
+                let socket = match pending.take(&caps) {
+                    Some(socket) => socket,
+                    None if pending.len() >= DEFERRED_LIMIT => break,
+                    None => {
+                        let Some(socket) = self.next_socket(&mut state, &probes, events).await
+                        else {
+                            if !counted {
+                                counted = true;
+                                count_hosts(&mut state.hosts, &probes, events).await;
+                            }
+                            break;
+                        };
+                        if !caps.admits(socket.ip()) {
+                            pending.push_back(socket);
+                            continue;
+                        }
+                        socket
                     }
+                };
+                caps.acquire(socket.ip());
+                let deadline = self.host_deadline_of(socket, &state.hosts);
+                ftrs.push(self.scan_until(socket, deadline, &probes));
+            }
+
+            let (socket, outcome) = match future::select(ftrs.next(), stop_signal.as_mut()).await {
+                Either::Left((Some(completed), _)) => completed,
+                Either::Left((None, _)) => break,
+                Either::Right((reason, _)) => {
+                    state.summary.stopped = Some(reason);
+                    break;
                 }
-                Err(e) => {
+            };
+
+            caps.release(socket.ip());
+            let signal = outcome.as_ref().and_then(Signal::from_outcome);
+            if let Some(signal) = signal {
+                window.record(signal, ftrs.len());
+            }
+            // Once some of our own sockets are closed there will be room for
+            // this one again. With nothing in flight, retrying won't help.
+            if signal == Some(Signal::ResourceExhausted) && !ftrs.is_empty() {
+                pending.push_front(socket);
+                continue;
+            }
+
+            match outcome {
+                Some(Ok(result)) => {
+                    state.summary.record(&result);
+                    if let Some(rtt) = result.rtt {
+                        probes.rtts.lock().unwrap().record(socket.ip(), rtt);
+                    }
+                    let tally = state.hosts.entry(socket.ip()).or_default();
+                    tally.record(result.state);
+                    let dead = self.dead_host_reason(socket.ip(), tally);
+                    emit(events, ScanEvent::PortResult(result.clone())).await;
+                    if self.all_results || result.state.is_open() {
+                        results.push(result);
+                    }
+                    if let Some(reason) = dead {
+                        skip_host(socket.ip(), reason, &mut state.hosts, events).await;
+                    }
+                }
+                Some(Err(e)) => {
+                    state.summary.record_error();
                     let error_string = e.to_string();
+                    emit(
+                        events,
+                        ScanEvent::Error {
+                            socket,
+                            kind: ScanErrorKind::from_error(&e),
+                            message: error_string.clone(),
+                        },
+                    )
+                    .await;
                     if errors.len() < self.ips.len() * 1000 {
                         errors.insert(error_string);
                     }
                 }
+                // The host ran out of time while this probe was in flight.
+                None => state.summary.unscanned += 1,
             }
+            state.finished_scans += 1;
+            state.progress.finish(socket);
+            finish_socket(socket.ip(), &mut state.hosts, &probes, events).await;
 
-            finished_scans += 1.0;
-            if let Some(ref progress_cb) = on_progress {
-                // Update progress every 10 scans for smoother GUI feedback
-                if (finished_scans as usize).is_multiple_of(10) {
-                    progress_cb(finished_scans / total_scans);
+            if last_progress.elapsed() >= PROGRESS_INTERVAL || state.finished_scans == total_scans {
+                last_progress = Instant::now();
+                // Only what this run did counts towards the ETA.
+                let so_far = ScanProgress::new(
+                    state.finished_scans - resumed,
+                    total_scans - resumed,
+                    start.elapsed(),
+                );
+                emit(events, ScanEvent::Progress(so_far)).await;
+            }
+            if let Some(path) = &self.checkpoint {
+                if last_checkpoint.elapsed() >= CHECKPOINT_INTERVAL {
+                    last_checkpoint = Instant::now();
+                    self.save_checkpoint(
+                        path,
+                        &mut state.progress,
+                        state.sockets.next_index(),
+                        &results,
+                    );
                 }
             }
         }
 
-        // Ensure we hit 100% at the end
-        if let Some(ref progress_cb) = on_progress {
-            progress_cb(1.0);
+        if let Some(reason) = state.summary.stopped {
+            debug!("Scan stopped early: {:?}", reason);
+            // In-flight probes are dropped along with `ftrs`.
+            state.summary.unscanned += total_scans - state.finished_scans;
         }
+        state.summary.duration = start.elapsed();
+        if let Some(path) = &self.checkpoint {
+            self.save_checkpoint(
+                path,
+                &mut state.progress,
+                state.sockets.next_index(),
+                &results,
+            );
+        }
+        emit(events, ScanEvent::Finished(state.summary)).await;
 
         debug!("Typical socket connection errors {:?}", errors);
-        debug!("Open Sockets found: {:?}", &open_sockets);
-        open_sockets
+        debug!(
+            "Open Sockets found: {:?}",
+            results
+                .iter()
+                .filter(|result| result.state.is_open())
+                .map(|result| result.socket)
+                .collect::<Vec<_>>()
+        );
+        let host_rtts = probes.rtts.lock().unwrap().srtts();
+        let skipped_hosts = state
+            .hosts
+            .iter()
+            .filter_map(|(ip, tally)| Some((*ip, tally.skipped?)))
+            .collect();
+        ScanReport {
+            results,
+            summary: state.summary,
+            host_rtts,
+            skipped_hosts,
+            shard: self.shard,
+        }
+    }
+
+    /// Pulls the next socket worth probing from the iterator. Hosts are
+    /// announced the first time one of their sockets comes up, sockets of
+    /// skipped hosts, or that a resumed scan already went through, are
+    /// passed over.
+    async fn next_socket(
+        &self,
+        state: &mut ScanState<'_>,
+        probes: &Probes,
+        events: Option<&Sender<ScanEvent>>,
+    ) -> Option<SocketAddr> {
+        let hosts = &mut state.hosts;
+        loop {
+            let index = state.sockets.next_index();
+            let socket = state.sockets.next()?;
+            let tally = hosts.entry(socket.ip()).or_default();
+            if !tally.counted {
+                tally.remaining += 1;
+            }
+            if state.progress.is_done(index) {
+                state.finished_scans += 1;
+                finish_socket(socket.ip(), hosts, probes, events).await;
+                continue;
+            }
+            state.progress.start(socket, index);
+
+            let now = Instant::now();
+            let tally = hosts.entry(socket.ip()).or_default();
+            let started_at = match tally.started_at {
+                Some(started_at) => started_at,
+                None => {
+                    tally.started_at = Some(now);
+                    emit(events, ScanEvent::HostStarted { ip: socket.ip() }).await;
+                    now
+                }
+            };
+
+            let expired = self
+                .host_deadline
+                .is_some_and(|budget| started_at + budget <= now);
+            if expired {
+                skip_host(socket.ip(), SkipReason::DeadlineExceeded, hosts, events).await;
+            }
+            if hosts[&socket.ip()].skipped.is_some() {
+                state.summary.unscanned += 1;
+                state.finished_scans += 1;
+                state.progress.finish(socket);
+                finish_socket(socket.ip(), hosts, probes, events).await;
+                continue;
+            }
+
+            return Some(socket);
+        }
+    }
+
+    /// Writes down how far the scan got. A failure is logged, the scan
+    /// itself goes on.
+    fn save_checkpoint(
+        &self,
+        path: &Path,
+        progress: &mut Progress,
+        position: usize,
+        results: &[ScanResult],
+    ) {
+        let (position, done) = progress.snapshot(position);
+        let checkpoint = Checkpoint {
+            version: checkpoint::CHECKPOINT_VERSION,
+            ips: self.ips.clone(),
+            port_strategy: self.port_strategy.clone(),
+            exclude_ports: self.exclude_ports.clone(),
+            udp: self.udp,
+            shard: self.shard,
+            socket_seed: self.socket_seed,
+            position,
+            done,
+            results: results
+                .iter()
+                .filter(|result| result.state.is_open())
+                .cloned()
+                .collect(),
+        };
+        if let Err(e) = checkpoint.save(path) {
+            log::warn!("Could not save the checkpoint to {}: {}", path.display(), e);
+        }
+    }
+
+    /// When the host of `socket` runs out of time, if it has a deadline.
+    /// A host skipped since the socket was queued already has.
+    fn host_deadline_of(
+        &self,
+        socket: SocketAddr,
+        hosts: &HashMap<IpAddr, HostTally>,
+    ) -> Option<Instant> {
+        let tally = hosts.get(&socket.ip())?;
+        if tally.skipped.is_some() {
+            return Some(Instant::now());
+        }
+        let started_at = tally.started_at?;
+        self.host_deadline.map(|budget| started_at + budget)
+    }
+
+    /// Why `ip` should be skipped from now on, if it looks down, see
+    /// `with_dead_host_threshold`.
+    fn dead_host_reason(&self, ip: IpAddr, tally: &HostTally) -> Option<SkipReason> {
+        let threshold = self.dead_host_threshold?;
+        let down = !tally.answered && tally.scanned >= threshold && !self.live_hosts.contains(&ip);
+        down.then_some(SkipReason::Unresponsive {
+            probes: tally.scanned,
+        })
+    }
+
+    /// Scans a socket, giving up once `deadline` is reached.
+    /// Returns `None` when the socket could not be scanned in time.
+    async fn scan_until(
+        &self,
+        socket: SocketAddr,
+        deadline: Option<Instant>,
+        probes: &Probes,
+    ) -> (SocketAddr, Option<io::Result<ScanResult>>) {
+        if deadline.is_some_and(|deadline| deadline <= Instant::now()) {
+            return (socket, None);
+        }
+        let scan = self.scan_socket(socket, probes);
+        let outcome = match deadline {
+            Some(deadline) => {
+                let budget = deadline.saturating_duration_since(Instant::now());
+                async_std::future::timeout(budget, scan).await.ok()
+            }
+            None => Some(scan.await),
+        };
+        (socket, outcome)
+    }
+
+    /// How long to wait for an answer from `ip`.
+    fn timeout_for(&self, ip: IpAddr, probes: &Probes) -> Duration {
+        match self.timeout_bounds {
+            Some((min, max)) => probes
+                .rtts
+                .lock()
+                .unwrap()
+                .timeout(ip, self.timeout, min, max),
+            None => self.timeout,
+        }
+    }
+
+    /// Why the scan should stop right now, if it should.
+    fn stop_reason(&self, start: Instant) -> Option<StopReason> {
+        if self.cancel.as_ref().is_some_and(CancelToken::is_cancelled) {
+            return Some(StopReason::Cancelled);
+        }
+        if self
+            .deadline
+            .is_some_and(|deadline| start.elapsed() >= deadline)
+        {
+            return Some(StopReason::DeadlineExceeded);
+        }
+        None
+    }
+
+    /// Resolves when the scan is cancelled or reaches its overall deadline,
+    /// so that a stop request doesn't have to wait for the next probe to finish.
+    async fn stop_signal(&self) -> StopReason {
+        let cancelled = async {
+            match &self.cancel {
+                Some(token) => token.cancelled().await,
+                None => future::pending().await,
+            }
+            StopReason::Cancelled
+        };
+        let deadline_reached = async {
+            match self.deadline {
+                Some(deadline) => async_std::task::sleep(deadline).await,
+                None => future::pending().await,
+            }
+            StopReason::DeadlineExceeded
+        };
+        future::select(Box::pin(cancelled), Box::pin(deadline_reached))
+            .await
+            .factor_first()
+            .0
     }
 
     /// Given a socket, scan it self.tries times.
     /// Turns the address into a SocketAddr
     /// Deals with the `<result>` type
-    /// If it experiences error ErrorKind::Other then too many files are open and it Panics!
-    /// Else any other error, it returns the error in Result as a string
-    /// If no errors occur, it returns the port number in Result to signify the port is open.
+    /// Running out of file descriptors is returned as is, so the scan loop can back off.
+    /// Errors that tell us something about the target are classified into a
+    /// `PortState`: a refusal means closed, a timeout means filtered.
+    /// Only timeouts are retried, a refusal is a definitive answer.
+    /// Any other error is returned in Result as a string.
     /// This function mainly deals with the logic of Results handling.
     /// # Example
     ///
@@ -158,40 +627,64 @@ impl Scanner {
     /// ```
     ///
     /// Note: `self` must contain `self.ip`.
-    async fn scan_socket(
-        &self,
-        socket: SocketAddr,
-        udp_map: &BTreeMap<Vec<u16>, Vec<u8>>,
-    ) -> io::Result<SocketAddr> {
+    async fn scan_socket(&self, socket: SocketAddr, probes: &Probes) -> io::Result<ScanResult> {
         if self.udp {
-            return self.scan_udp_socket(socket, udp_map).await;
+            return self.scan_udp_socket(socket, probes).await;
+        }
+        #[cfg(unix)]
+        if let (Some(syn), SocketAddr::V4(target)) = (&self.syn, socket) {
+            return self.scan_syn_socket(syn, target, probes).await;
         }
+        self.scan_tcp_socket(socket, probes).await
+    }
 
+    async fn scan_tcp_socket(&self, socket: SocketAddr, probes: &Probes) -> io::Result<ScanResult> {
         let tries = self.tries.get();
         for nr_try in 1..=tries {
-            match self.connect(socket).await {
-                Ok(tcp_stream) => {
+            pace(probes.limiter.as_ref()).await;
+            let timeout = self.timeout_for(socket.ip(), probes);
+            let start = Instant::now();
+            match self.connect(socket, timeout).await {
+                Ok(mut tcp_stream) => {
+                    let rtt = start.elapsed();
+                    let banner = match &self.banner_grab {
+                        Some(options) => grab_banner(&mut tcp_stream, socket.port(), options).await,
+                        None => None,
+                    };
                     debug!(
                         "Connection was successful, shutting down stream {}",
                         &socket
                     );
-                    if let Err(e) = tcp_stream.shutdown(Shutdown::Both) {
+                    if let Err(e) = futures::AsyncWriteExt::close(&mut tcp_stream).await {
                         debug!("Shutdown stream error {}", &e);
                     }
-                    self.fmt_ports(socket);
 
                     debug!("Return Ok after {} tries", nr_try);
-                    return Ok(socket);
+                    return Ok(ScanResult::new(socket, PortState::Open)
+                        .with_rtt(rtt)
+                        .with_banner(banner));
                 }
                 Err(e) => {
+                    if ScanErrorKind::from_error(&e) == ScanErrorKind::ResourceExhausted {
+                        // Retrying right away won't help, the scan loop backs
+                        // off and queues this socket again.
+                        return Err(e);
+                    }
                     let mut error_string = e.to_string();
 
-                    assert!(!error_string.to_lowercase().contains("too many open files"), "Too many open files. Please reduce batch size. The default is 5000. Try -b 2500.");
-
-                    if nr_try == tries {
-                        error_string.push(' ');
-                        error_string.push_str(&socket.ip().to_string());
-                        return Err(io::Error::other(error_string));
+                    match PortState::from_error(&e) {
+                        Some(PortState::Filtered) if nr_try < tries => continue,
+                        Some(PortState::Closed) => {
+                            return Ok(ScanResult::new(socket, PortState::Closed)
+                                .with_rtt(start.elapsed()))
+                        }
+                        Some(state) => return Ok(ScanResult::new(socket, state)),
+                        None if nr_try < tries => continue,
+                        None => {
+                            error_string.push(' ');
+                            error_string.push_str(&socket.ip().to_string());
+                            return Err(io::Error::other(error_string));
+                        }
                     }
                 }
             };
@@ -199,31 +692,93 @@ impl Scanner {
         unreachable!();
     }
 
-    async fn scan_udp_socket(
+    #[cfg(unix)]
+    async fn scan_syn_socket(
         &self,
-        socket: SocketAddr,
-        udp_map: &BTreeMap<Vec<u16>, Vec<u8>>,
-    ) -> io::Result<SocketAddr> {
-        let mut payload: Vec<u8> = Vec::new();
-        for (key, value) in udp_map {
-            if key.contains(&socket.port()) {
-                payload = value.clone();
+        syn: &SynScanner,
+        target: std::net::SocketAddrV4,
+        probes: &Probes,
+    ) -> io::Result<ScanResult> {
+        let socket = SocketAddr::V4(target);
+        for _ in 0..self.tries.get() {
+            pace(probes.limiter.as_ref()).await;
+            let timeout = self.timeout_for(socket.ip(), probes);
+            let start = Instant::now();
+            match syn.probe(target, timeout).await? {
+                Some(Reply::SynAck) => {
+                    return Ok(ScanResult::new(socket, PortState::Open).with_rtt(start.elapsed()));
+                }
+                Some(Reply::Reset) => {
+                    return Ok(ScanResult::new(socket, PortState::Closed).with_rtt(start.elapsed()))
+                }
+                Some(Reply::Unreachable) => {
+                    return Ok(ScanResult::new(socket, PortState::Filtered))
+                }
+                None => continue,
             }
         }
+        Ok(ScanResult::new(socket, PortState::Filtered))
+    }
 
-        let tries = self.tries.get();
-        for _ in 1..=tries {
-            match self.udp_scan(socket, &payload, self.timeout).await {
-                Ok(true) => return Ok(socket),
-                Ok(false) => continue,
-                Err(e) => return Err(e),
+    async fn scan_udp_socket(&self, socket: SocketAddr, probes: &Probes) -> io::Result<ScanResult> {
+        let payloads = self
+            .udp_payloads
+            .as_ref()
+            .unwrap_or_else(|| UdpPayloads::builtin())
+            .for_port(socket.port());
+
+        // One socket per payload so we know which one got the answer. They
+        // are kept for every try: an ICMP error that arrives after its try
+        // timed out still closes the port on the next one. Sockets pinned
+        // to the same source port would all share one 4-tuple, so the
+        // payloads go out together from a single socket then.
+        let groups: Vec<&[Arc<UdpPayload>]> = if self.transport.distinct_udp_sockets() {
+            payloads.chunks(1).collect()
+        } else {
+            vec![payloads]
+        };
+        let mut udp_sockets = Vec::with_capacity(groups.len());
+        for _ in &groups {
+            udp_sockets.push(self.udp_bind(socket).await?);
+        }
+
+        for nr_try in 1..=self.tries.get() {
+            let wait = udp_backoff(self.timeout_for(socket.ip(), probes), nr_try);
+            let mut answers: FuturesUnordered<_> = udp_sockets
+                .iter()
+                .zip(&groups)
+                .map(|(udp_socket, group)| async move {
+                    let answer = self.udp_scan(udp_socket, group, wait, probes).await;
+                    (*group, answer)
+                })
+                .collect();
+
+            while let Some((group, answer)) = answers.next().await {
+                match answer? {
+                    Some((UdpReply::Answer(size), rtt)) => {
+                        debug!("Received {} bytes", size);
+                        let result = ScanResult::new(socket, PortState::Open).with_rtt(rtt);
+                        return Ok(match group {
+                            [payload] => result.with_udp_payload(&payload.name),
+                            _ => result,
+                        });
+                    }
+                    Some((UdpReply::Icmp(event), rtt)) => {
+                        return Ok(match event.port_state() {
+                            PortState::Closed => {
+                                ScanResult::new(socket, PortState::Closed).with_rtt(rtt)
+                            }
+                            state => ScanResult::new(socket, state),
+                        })
+                    }
+                    None => continue,
+                }
             }
         }
 
-        Err(io::Error::other(format!(
-            "UDP scan timed-out for all tries on socket {}",
-            socket
-        )))
+        // No answer at all: either the service ignored our payload or a
+        // firewall dropped it, UDP gives us no way to tell these apart.
+        Ok(ScanResult::new(socket, PortState::OpenFiltered))
     }
 
     /// Performs the connection to the socket with timeout
@@ -237,19 +792,14 @@ impl Scanner {
     /// let socket = SocketAddr::new(ip, port);
     /// scanner.connect(socket);
     /// // returns Result which is either Ok(stream) for port is open, or Er for port is closed.
-    /// // Timeout occurs after self.timeout seconds
+    /// // Timeout occurs after `timeout`
     /// ```
     ///
-    async fn connect(&self, socket: SocketAddr) -> io::Result<TcpStream> {
-        let stream = io::timeout(
-            self.timeout,
-            async move { TcpStream::connect(socket).await },
-        )
-        .await?;
-        Ok(stream)
+    async fn connect(&self, socket: SocketAddr, timeout: Duration) -> io::Result<T::TcpStream> {
+        self.transport.connect_within(socket, timeout).await
     }
 
-    /// Binds to a UDP socket so we can send and receive packets
+    /// Binds to a UDP socket that only talks to `socket`
     /// # Example
     ///
     /// ```compile_fail
@@ -263,16 +813,12 @@ impl Scanner {
     /// // Timeout occurs after self.timeout seconds
     /// ```
     ///
-    async fn udp_bind(&self, socket: SocketAddr) -> io::Result<UdpSocket> {
-        let local_addr = match socket {
-            SocketAddr::V4(_) => "0.0.0.0:0".parse::<SocketAddr>().unwrap(),
-            SocketAddr::V6(_) => "[::]:0".parse::<SocketAddr>().unwrap(),
-        };
-
-        UdpSocket::bind(local_addr).await
+    async fn udp_bind(&self, socket: SocketAddr) -> io::Result<T::UdpSocket> {
+        self.transport.udp_socket(socket).await
     }
 
-    /// Performs a UDP scan on the specified socket with a payload and wait duration
+    /// Sends `payloads` over `udp_socket` and waits up to `wait` for what
+    /// comes back, timed from the last one sent.
     /// # Example
     ///
     /// ```compile_fail
@@ -282,63 +828,166 @@ impl Scanner {
     /// // ip is an IpAddr type
     /// let ip = IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1));
     /// let socket = SocketAddr::new(ip, port);
-    /// let payload = vec![0, 1, 2, 3];
+    /// let udp_socket = scanner.udp_bind(socket).await?;
+    /// let payloads = UdpPayloads::builtin().for_port(port);
     /// let wait = Duration::from_secs(1);
-    /// let result = scanner.udp_scan(socket, payload, wait).await;
-    /// // returns Result which is either Ok(true) if response received, or Ok(false) if timed out.
-    /// // Err is returned for other I/O errors.
+    /// let result = scanner.udp_scan(&udp_socket, payloads, wait, &probes).await;
+    /// // returns Result which is either Ok(Some((reply, rtt))) for an answer or an ICMP error,
+    /// // or Ok(None) if timed out. Err is returned for other I/O errors.
     async fn udp_scan(
         &self,
-        socket: SocketAddr,
-        payload: &[u8],
+        udp_socket: &T::UdpSocket,
+        payloads: &[Arc<UdpPayload>],
         wait: Duration,
-    ) -> io::Result<bool> {
-        match self.udp_bind(socket).await {
-            Ok(udp_socket) => {
-                let mut buf = [0u8; 1024];
+        probes: &Probes,
+    ) -> io::Result<Option<(UdpReply, Duration)>> {
+        let mut buf = [0u8; 1024];
+        let mut start = Instant::now();
+        for payload in payloads {
+            pace(probes.limiter.as_ref()).await;
+            start = Instant::now();
+            self.transport.send(udp_socket, &payload.data).await?;
+        }
+
+        match io::timeout(wait, self.transport.recv(udp_socket, &mut buf)).await {
+            Ok(reply) => Ok(Some((reply, start.elapsed()))),
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
 
-                udp_socket.connect(socket).await?;
-                udp_socket.send(payload).await?;
+/// How long to wait for an answer to the `nr_try`th UDP probe.
+///
+/// Most stacks rate limit ICMP port unreachable, Linux sends about one per
+/// second, so a closed port easily looks silent under load. Every retry
+/// waits twice as long as the one before, up to 8 times `timeout`.
+fn udp_backoff(timeout: Duration, nr_try: u8) -> Duration {
+    timeout * (1 << (nr_try.saturating_sub(1)).min(3))
+}
 
-                match io::timeout(wait, udp_socket.recv(&mut buf)).await {
-                    Ok(size) => {
-                        debug!("Received {} bytes", size);
-                        self.fmt_ports(socket);
-                        Ok(true)
-                    }
-                    Err(e) => {
-                        if e.kind() == io::ErrorKind::TimedOut {
-                            Ok(false)
-                        } else {
-                            Err(e)
-                        }
-                    }
-                }
-            }
-            Err(e) => {
-                println!("Err E binding sock {:?}", e);
-                Err(e)
-            }
+/// What a running scan keeps track of, apart from the probes in flight.
+struct ScanState<'s> {
+    sockets: SocketIterator<'s>,
+    progress: Progress,
+    hosts: HashMap<IpAddr, HostTally>,
+    summary: ScanSummary,
+    /// Sockets done so far, including those of the run a resumed scan
+    /// picks up from.
+    finished_scans: usize,
+}
+
+/// Per-host bookkeeping used to emit `HostStarted`/`HostFinished` and to
+/// decide when to skip the rest of a host.
+#[derive(Debug, Default)]
+struct HostTally {
+    started_at: Option<Instant>,
+    /// Sockets of the host not done yet.
+    remaining: usize,
+    /// Whether `remaining` holds every socket the host has left. Shuffled
+    /// scans only know once the iterator runs out, until then it goes up
+    /// as sockets come up.
+    counted: bool,
+    open: usize,
+    /// How many sockets of the host were classified so far.
+    scanned: usize,
+    /// Whether the host itself ever answered, open or closed.
+    answered: bool,
+    skipped: Option<SkipReason>,
+}
+
+impl HostTally {
+    fn record(&mut self, state: PortState) {
+        self.scanned += 1;
+        self.open += usize::from(state.is_open());
+        self.answered |= matches!(state, PortState::Open | PortState::Closed);
+    }
+}
+
+/// State shared by every probe of a scan.
+#[derive(Debug, Default)]
+struct Probes {
+    limiter: Option<Mutex<RateLimiter>>,
+    rtts: Mutex<HostRtts>,
+}
+
+/// Leaves the sockets of `ip` that were not probed yet out of the scan.
+/// Only the first reason counts, and is announced.
+async fn skip_host(
+    ip: IpAddr,
+    reason: SkipReason,
+    hosts: &mut HashMap<IpAddr, HostTally>,
+    events: Option<&Sender<ScanEvent>>,
+) {
+    let tally = hosts.entry(ip).or_default();
+    if tally.skipped.is_none() {
+        tally.skipped = Some(reason);
+        emit(events, ScanEvent::HostSkipped { ip, reason }).await;
+    }
+}
+
+/// Accounts for one socket of `ip` and announces the host once all of its
+/// sockets are done.
+async fn finish_socket(
+    ip: IpAddr,
+    hosts: &mut HashMap<IpAddr, HostTally>,
+    probes: &Probes,
+    events: Option<&Sender<ScanEvent>>,
+) {
+    if let Some(tally) = hosts.get_mut(&ip) {
+        tally.remaining = tally.remaining.saturating_sub(1);
+        if tally.remaining == 0 && tally.counted {
+            host_finished(ip, tally, probes, events).await;
         }
     }
+}
 
-    /// Formats and prints the port status
-    fn fmt_ports(&self, socket: SocketAddr) {
-        if !self.greppable {
-            if self.accessible {
-                println!("Open {socket}");
-            } else {
-                println!("Open {}", socket.to_string().purple());
-            }
+/// Settles the hosts whose sockets were counted as they came up, once
+/// there are no more to come. Those with nothing in flight are done.
+async fn count_hosts(
+    hosts: &mut HashMap<IpAddr, HostTally>,
+    probes: &Probes,
+    events: Option<&Sender<ScanEvent>>,
+) {
+    for (ip, tally) in hosts.iter_mut().filter(|(_, tally)| !tally.counted) {
+        tally.counted = true;
+        if tally.remaining == 0 && tally.started_at.is_some() {
+            host_finished(*ip, tally, probes, events).await;
         }
     }
 }
 
+async fn host_finished(
+    ip: IpAddr,
+    tally: &HostTally,
+    probes: &Probes,
+    events: Option<&Sender<ScanEvent>>,
+) {
+    let srtt = probes.rtts.lock().unwrap().srtt(ip);
+    emit(
+        events,
+        ScanEvent::HostFinished {
+            ip,
+            open_ports: tally.open,
+            srtt,
+        },
+    )
+    .await;
+}
+
+async fn emit(events: Option<&Sender<ScanEvent>>, event: ScanEvent) {
+    if let Some(sender) = events {
+        // A consumer that went away is not a reason to abort the scan.
+        let _ = sender.send(event).await;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::input::{PortRange, ScanOrder};
     use async_std::task::block_on;
+    use futures::StreamExt;
     use std::{net::IpAddr, time::Duration};
 
     #[test]
@@ -350,18 +999,13 @@ mod tests {
             end: 1_000,
         };
         let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
-        let scanner = Scanner::new(
-            &addrs,
-            10,
-            Duration::from_millis(100),
-            1,
-            true,
-            strategy,
-            true,
-            vec![9000],
-            false,
-        );
-        block_on(scanner.run(None, None));
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(100))
+            .with_port_strategy(strategy)
+            .with_exclude_ports(vec![9000])
+            .build();
+        block_on(scanner.run());
         // if the scan fails, it wouldn't be able to assert_eq! as it panicked!
         assert_eq!(1, 1);
     }
@@ -374,18 +1018,13 @@ mod tests {
             end: 1_000,
         };
         let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
-        let scanner = Scanner::new(
-            &addrs,
-            10,
-            Duration::from_millis(100),
-            1,
-            true,
-            strategy,
-            true,
-            vec![9000],
-            false,
-        );
-        block_on(scanner.run(None, None));
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(100))
+            .with_port_strategy(strategy)
+            .with_exclude_ports(vec![9000])
+            .build();
+        block_on(scanner.run());
         // if the scan fails, it wouldn't be able to assert_eq! as it panicked!
         assert_eq!(1, 1);
     }
@@ -397,18 +1036,13 @@ mod tests {
             end: 1_000,
         };
         let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
-        let scanner = Scanner::new(
-            &addrs,
-            10,
-            Duration::from_millis(100),
-            1,
-            true,
-            strategy,
-            true,
-            vec![9000],
-            false,
-        );
-        block_on(scanner.run(None, None));
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(100))
+            .with_port_strategy(strategy)
+            .with_exclude_ports(vec![9000])
+            .build();
+        block_on(scanner.run());
         assert_eq!(1, 1);
     }
     #[test]
@@ -419,18 +1053,13 @@ mod tests {
             end: 445,
         };
         let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
-        let scanner = Scanner::new(
-            &addrs,
-            10,
-            Duration::from_millis(100),
-            1,
-            true,
-            strategy,
-            true,
-            vec![9000],
-            false,
-        );
-        block_on(scanner.run(None, None));
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(100))
+            .with_port_strategy(strategy)
+            .with_exclude_ports(vec![9000])
+            .build();
+        block_on(scanner.run());
         assert_eq!(1, 1);
     }
     #[test]
@@ -444,21 +1073,519 @@ mod tests {
             end: 600,
         };
         let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
-        let scanner = Scanner::new(
-            &addrs,
-            10,
-            Duration::from_millis(100),
-            1,
-            true,
-            strategy,
-            true,
-            vec![9000],
-            false,
-        );
-        block_on(scanner.run(None, None));
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(100))
+            .with_port_strategy(strategy)
+            .with_exclude_ports(vec![9000])
+            .build();
+        block_on(scanner.run());
         assert_eq!(1, 1);
     }
 
+    #[test]
+    fn scanner_tells_open_and_closed_apart() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let open_port = listener.local_addr().unwrap().port();
+        let closed_port = {
+            let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy =
+            PortStrategy::pick(&None, Some(vec![open_port, closed_port]), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(500))
+            .with_port_strategy(strategy)
+            .with_all_results(true)
+            .build();
+        let report = block_on(scanner.run());
+        assert!(report.is_complete());
+
+        let state_of = |port: u16| {
+            report
+                .results
+                .iter()
+                .find(|result| result.socket.port() == port)
+                .map(|result| result.state)
+        };
+        assert_eq!(state_of(open_port), Some(PortState::Open));
+        assert_eq!(state_of(closed_port), Some(PortState::Closed));
+    }
+
+    #[test]
+    fn resumed_scan_skips_what_is_done() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let open_port = listener.local_addr().unwrap().port();
+        let closed_port = {
+            let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+        let localhost = "127.0.0.1".parse::<IpAddr>().unwrap();
+        // Found open by the first run, nothing listens there now.
+        let found_earlier = SocketAddr::new(localhost, closed_port);
+
+        let ports = vec![closed_port, open_port, closed_port, 1];
+        let checkpoint = Checkpoint {
+            version: checkpoint::CHECKPOINT_VERSION,
+            ips: vec![localhost],
+            port_strategy: PortStrategy::pick(&None, Some(ports), ScanOrder::Serial),
+            exclude_ports: vec![],
+            udp: false,
+            shard: None,
+            socket_seed: None,
+            position: 1,
+            done: vec![3],
+            results: vec![ScanResult::new(found_earlier, PortState::Open)],
+        };
+        let path = std::env::temp_dir().join(format!("scannr-resume-{}.json", std::process::id()));
+        let scanner = Scanner::builder(&[])
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(500))
+            .with_port_strategy(PortStrategy::pick(&None, Some(vec![]), ScanOrder::Serial))
+            .with_all_results(true)
+            .with_resume(checkpoint)
+            .with_checkpoint(path.clone())
+            .build();
+        let report = block_on(scanner.run());
+
+        let scanned: Vec<(u16, PortState)> = report
+            .results
+            .iter()
+            .map(|result| (result.socket.port(), result.state))
+            .collect();
+        // Only the socket at index 1 and the one left pending at 2 are probed.
+        assert_eq!(scanned.len(), 3);
+        assert!(scanned.contains(&(closed_port, PortState::Open)));
+        assert!(scanned.contains(&(open_port, PortState::Open)));
+        assert!(scanned.contains(&(closed_port, PortState::Closed)));
+
+        let saved = Checkpoint::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!((saved.position, saved.done), (4, vec![]));
+        assert_eq!(saved.results.len(), 2);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn syn_scan_tells_open_and_closed_apart() {
+        // Raw sockets need privileges the test runner may not have.
+        let Ok(syn) = SynScanner::new() else {
+            return;
+        };
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let open_port = listener.local_addr().unwrap().port();
+        let closed_port = {
+            let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy =
+            PortStrategy::pick(&None, Some(vec![open_port, closed_port]), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(500))
+            .with_tries(2)
+            .with_port_strategy(strategy)
+            .with_all_results(true)
+            .with_syn(syn)
+            .build();
+        let report = block_on(scanner.run());
+
+        let state_of = |port: u16| {
+            report
+                .results
+                .iter()
+                .find(|result| result.socket.port() == port)
+                .map(|result| result.state)
+        };
+        assert_eq!(state_of(open_port), Some(PortState::Open));
+        assert_eq!(state_of(closed_port), Some(PortState::Closed));
+    }
+
+    #[test]
+    fn banner_grab_attaches_the_greeting() {
+        use std::io::Write;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            stream.write_all(b"220 ftp.example.com ready\r\n").unwrap();
+            std::thread::sleep(Duration::from_secs(1));
+        });
+
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy = PortStrategy::pick(&None, Some(vec![port]), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(500))
+            .with_port_strategy(strategy)
+            .with_banner_grab(BannerGrab {
+                timeout: Duration::from_millis(300),
+                max_bytes: 256,
+            })
+            .build();
+        let report = block_on(scanner.run());
+
+        let banner = report.results[0].banner.as_ref().unwrap();
+        assert_eq!(banner.to_string(), "220 ftp.example.com ready");
+    }
+
+    #[test]
+    fn events_describe_the_whole_scan() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let open_port = listener.local_addr().unwrap().port();
+
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy = PortStrategy::pick(&None, Some(vec![open_port]), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(500))
+            .with_port_strategy(strategy)
+            .build();
+        let events: Vec<ScanEvent> = block_on(scanner.events().collect());
+
+        assert!(matches!(
+            events.first(),
+            Some(ScanEvent::HostStarted { .. })
+        ));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            ScanEvent::PortResult(result) if result.state == PortState::Open && result.rtt.is_some()
+        )));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            ScanEvent::HostFinished {
+                open_ports: 1,
+                srtt: Some(_),
+                ..
+            }
+        )));
+        match events.last() {
+            Some(ScanEvent::Finished(summary)) => {
+                assert_eq!(summary.sockets, 1);
+                assert_eq!(summary.open, 1);
+            }
+            other => panic!("expected Finished as last event, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn adaptive_scan_finds_open_port() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let open_port = listener.local_addr().unwrap().port();
+
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let mut ports: Vec<u16> = (1..=1_000).collect();
+        ports.push(open_port);
+        let strategy = PortStrategy::pick(&None, Some(ports), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10_000)
+            .with_timeout(Duration::from_millis(500))
+            .with_port_strategy(strategy)
+            .with_adaptive(true)
+            .build();
+
+        let report = block_on(scanner.run());
+        assert!(report.is_complete());
+        assert_eq!(report.summary.sockets, 1_001);
+        assert!(report
+            .open_sockets()
+            .any(|socket| socket.port() == open_port));
+    }
+
+    #[test]
+    fn max_rate_spaces_probes() {
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy = PortStrategy::pick(&None, Some(vec![1, 2, 3, 4, 5]), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(500))
+            .with_port_strategy(strategy)
+            .with_max_rate(20)
+            .build();
+
+        let started = Instant::now();
+        let report = block_on(scanner.run());
+        // Five probes at 20 per second leave four 50ms gaps.
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        assert_eq!(report.summary.sockets, 5);
+    }
+
+    #[test]
+    fn host_cap_still_scans_every_socket() {
+        // More sockets than DEFERRED_LIMIT for a single host.
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let range = PortRange {
+            start: 1,
+            end: 5_000,
+        };
+        let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(100)
+            .with_timeout(Duration::from_millis(500))
+            .with_port_strategy(strategy)
+            .with_max_per_host(2)
+            .with_max_per_subnet(4)
+            .build();
+
+        let report = block_on(scanner.run());
+        assert!(report.is_complete());
+        assert_eq!(report.summary.sockets, 5_000);
+    }
+
+    #[test]
+    fn dynamic_timeout_follows_the_host() {
+        // One UDP port answers once, which tells the scanner the host is
+        // close. The silent one should then time out well before `timeout`.
+        let echo = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let echo_port = echo.local_addr().unwrap().port();
+        let silent_port = silent.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let mut buf = [0u8; 1024];
+            if let Ok((_, peer)) = echo.recv_from(&mut buf) {
+                let _ = echo.send_to(b"pong", peer);
+            }
+        });
+
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy =
+            PortStrategy::pick(&None, Some(vec![echo_port, silent_port]), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(1)
+            .with_timeout(Duration::from_secs(5))
+            .with_port_strategy(strategy)
+            .with_udp(true)
+            .with_dynamic_timeout(Duration::from_millis(50), Duration::from_millis(200))
+            .build();
+
+        let started = Instant::now();
+        let report = block_on(scanner.run());
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert!(report.host_rtts.contains_key(&addrs[0]));
+        assert_eq!(report.summary.open, 1);
+        assert_eq!(report.summary.open_filtered, 1);
+    }
+
+    #[test]
+    fn cancelled_scan_returns_partial_report() {
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let range = PortRange {
+            start: 1,
+            end: 1_000,
+        };
+        let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Serial);
+        let token = CancelToken::new();
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(100))
+            .with_port_strategy(strategy)
+            .with_cancel_token(token.clone())
+            .build();
+        token.cancel();
+
+        let report = block_on(scanner.run());
+        assert!(!report.is_complete());
+        assert_eq!(report.summary.stopped, Some(StopReason::Cancelled));
+        assert_eq!(report.summary.sockets + report.summary.unscanned, 1_000);
+    }
+
+    #[test]
+    fn deadline_stops_the_scan() {
+        // A bound UDP socket that never answers keeps the probe in flight
+        // until the (long) scan timeout.
+        let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = silent.local_addr().unwrap().port();
+
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy = PortStrategy::pick(&None, Some(vec![port]), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_secs(5))
+            .with_port_strategy(strategy)
+            .with_udp(true)
+            .with_deadline(Duration::from_millis(100))
+            .build();
+
+        let started = Instant::now();
+        let report = block_on(scanner.run());
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(report.summary.stopped, Some(StopReason::DeadlineExceeded));
+        assert_eq!(report.summary.unscanned, 1);
+    }
+
+    #[test]
+    fn host_deadline_skips_remaining_sockets() {
+        let silent: Vec<std::net::UdpSocket> = (0..3)
+            .map(|_| std::net::UdpSocket::bind("127.0.0.1:0").unwrap())
+            .collect();
+        let ports = silent
+            .iter()
+            .map(|socket| socket.local_addr().unwrap().port())
+            .collect();
+
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy = PortStrategy::pick(&None, Some(ports), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(1)
+            .with_timeout(Duration::from_secs(5))
+            .with_port_strategy(strategy)
+            .with_udp(true)
+            .with_host_deadline(Duration::from_millis(100))
+            .build();
+
+        let started = Instant::now();
+        let report = block_on(scanner.run());
+        assert!(started.elapsed() < Duration::from_secs(5));
+        // The host gave up, but the scan itself was not stopped.
+        assert_eq!(report.summary.stopped, None);
+        assert_eq!(report.summary.unscanned, 3);
+        assert_eq!(
+            report.skipped_hosts.get(&addrs[0]),
+            Some(&SkipReason::DeadlineExceeded)
+        );
+        assert!(!report.is_complete());
+    }
+
+    #[test]
+    fn udp_scan_tells_open_closed_and_silent_apart() {
+        let echo = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let open_port = echo.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let mut buf = [0; 1024];
+            while let Ok((size, from)) = echo.recv_from(&mut buf) {
+                let _ = echo.send_to(&buf[..size], from);
+            }
+        });
+        // Bound but never read from: the datagram is queued and unanswered.
+        let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let silent_port = silent.local_addr().unwrap().port();
+        let closed_port = {
+            let probe = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy = PortStrategy::pick(
+            &None,
+            Some(vec![open_port, silent_port, closed_port]),
+            ScanOrder::Serial,
+        );
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(200))
+            .with_tries(2)
+            .with_port_strategy(strategy)
+            .with_udp(true)
+            .with_all_results(true)
+            .build();
+        let report = block_on(scanner.run());
+
+        let state_of = |port: u16| {
+            report
+                .results
+                .iter()
+                .find(|result| result.socket.port() == port)
+                .map(|result| result.state)
+        };
+        assert_eq!(state_of(open_port), Some(PortState::Open));
+        assert_eq!(state_of(silent_port), Some(PortState::OpenFiltered));
+        assert_eq!(state_of(closed_port), Some(PortState::Closed));
+    }
+
+    #[test]
+    fn udp_scan_sends_every_payload_of_a_port() {
+        let server = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = server.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let mut buf = [0; 1024];
+            while let Ok((size, from)) = server.recv_from(&mut buf) {
+                if &buf[..size] == b"ping" {
+                    let _ = server.send_to(b"pong", from);
+                }
+            }
+        });
+
+        let payload = |name: &str, data: &[u8]| UdpPayload {
+            name: name.to_string(),
+            data: data.to_vec(),
+        };
+        let payloads = UdpPayloads::new([
+            (vec![port], payload("hello", b"hello")),
+            (vec![port], payload("ping", b"ping")),
+        ]);
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy = PortStrategy::pick(&None, Some(vec![port]), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(500))
+            .with_port_strategy(strategy)
+            .with_udp(true)
+            .with_udp_payloads(payloads)
+            .build();
+        let report = block_on(scanner.run());
+
+        assert_eq!(report.results.len(), 1);
+        assert_eq!(report.results[0].state, PortState::Open);
+        assert_eq!(report.results[0].udp_payload.as_deref(), Some("ping"));
+    }
+
+    #[test]
+    fn udp_payloads_share_a_pinned_source_port() {
+        let server = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = server.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let mut buf = [0; 1024];
+            while let Ok((size, from)) = server.recv_from(&mut buf) {
+                if &buf[..size] == b"ping" {
+                    let _ = server.send_to(b"pong", from);
+                }
+            }
+        });
+        let source_port = std::net::UdpSocket::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let payload = |name: &str, data: &[u8]| UdpPayload {
+            name: name.to_string(),
+            data: data.to_vec(),
+        };
+        let payloads = UdpPayloads::new([
+            (vec![port], payload("hello", b"hello")),
+            (vec![port], payload("ping", b"ping")),
+        ]);
+        let addrs = vec!["127.0.0.1".parse::<IpAddr>().unwrap()];
+        let strategy = PortStrategy::pick(&None, Some(vec![port]), ScanOrder::Serial);
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(500))
+            .with_port_strategy(strategy)
+            .with_udp(true)
+            .with_source(Source::default().with_ports(source_port..=source_port))
+            .with_udp_payloads(payloads)
+            .build();
+        let report = block_on(scanner.run());
+
+        assert_eq!(report.results.len(), 1);
+        assert_eq!(report.results[0].state, PortState::Open);
+        // Both went out from the same socket, no telling which one it was.
+        assert_eq!(report.results[0].udp_payload, None);
+    }
+
+    #[test]
+    fn udp_retries_wait_longer() {
+        let timeout = Duration::from_millis(100);
+        let waits: Vec<Duration> = (1..=5).map(|nr_try| udp_backoff(timeout, nr_try)).collect();
+        assert_eq!(waits, [100, 200, 400, 800, 800].map(Duration::from_millis));
+    }
+
     #[test]
     fn udp_scan_runs() {
         // Makes sure the program still runs and doesn't panic
@@ -468,18 +1595,14 @@ mod tests {
             end: 1_000,
         };
         let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
-        let scanner = Scanner::new(
-            &addrs,
-            10,
-            Duration::from_millis(100),
-            1,
-            true,
-            strategy,
-            true,
-            vec![9000],
-            true,
-        );
-        block_on(scanner.run(None, None));
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(100))
+            .with_port_strategy(strategy)
+            .with_exclude_ports(vec![9000])
+            .with_udp(true)
+            .build();
+        block_on(scanner.run());
         // if the scan fails, it wouldn't be able to assert_eq! as it panicked!
         assert_eq!(1, 1);
     }
@@ -492,18 +1615,14 @@ mod tests {
             end: 1_000,
         };
         let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
-        let scanner = Scanner::new(
-            &addrs,
-            10,
-            Duration::from_millis(100),
-            1,
-            true,
-            strategy,
-            true,
-            vec![9000],
-            true,
-        );
-        block_on(scanner.run(None, None));
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(100))
+            .with_port_strategy(strategy)
+            .with_exclude_ports(vec![9000])
+            .with_udp(true)
+            .build();
+        block_on(scanner.run());
         // if the scan fails, it wouldn't be able to assert_eq! as it panicked!
         assert_eq!(1, 1);
     }
@@ -515,18 +1634,14 @@ mod tests {
             end: 1_000,
         };
         let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
-        let scanner = Scanner::new(
-            &addrs,
-            10,
-            Duration::from_millis(100),
-            1,
-            true,
-            strategy,
-            true,
-            vec![9000],
-            true,
-        );
-        block_on(scanner.run(None, None));
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(100))
+            .with_port_strategy(strategy)
+            .with_exclude_ports(vec![9000])
+            .with_udp(true)
+            .build();
+        block_on(scanner.run());
         assert_eq!(1, 1);
     }
     #[test]
@@ -537,18 +1652,236 @@ mod tests {
             end: 150,
         };
         let strategy = PortStrategy::pick(&Some(range), None, ScanOrder::Random);
-        let scanner = Scanner::new(
-            &addrs,
-            10,
-            Duration::from_millis(100),
-            1,
-            true,
-            strategy,
-            true,
-            vec![9000],
-            true,
-        );
-        block_on(scanner.run(None, None));
+        let scanner = Scanner::builder(&addrs)
+            .with_batch_size(10)
+            .with_timeout(Duration::from_millis(100))
+            .with_port_strategy(strategy)
+            .with_exclude_ports(vec![9000])
+            .with_udp(true)
+            .build();
+        block_on(scanner.run());
         assert_eq!(1, 1);
     }
+
+    /// A scanner over `network` that probes `ports` of 192.0.2.1 and
+    /// 192.0.2.2 in serial order.
+    fn simulated_scanner(
+        network: &SimulatedNetwork,
+        ports: Vec<u16>,
+        batch_size: u16,
+        tries: u8,
+        udp: bool,
+    ) -> ScannerBuilder<SimulatedNetwork> {
+        let addrs: Vec<IpAddr> = vec!["192.0.2.1".parse().unwrap(), "192.0.2.2".parse().unwrap()];
+        let strategy = PortStrategy::pick(&None, Some(ports), ScanOrder::Serial);
+        Scanner::builder(&addrs)
+            .with_batch_size(batch_size)
+            .with_timeout(Duration::from_millis(50))
+            .with_tries(tries)
+            .with_port_strategy(strategy)
+            .with_udp(udp)
+            .with_all_results(true)
+            .with_transport(network.clone())
+    }
+
+    fn simulated_socket(host: u8, port: u16) -> SocketAddr {
+        SocketAddr::from(([192, 0, 2, host], port))
+    }
+
+    #[test]
+    fn simulated_answers_are_classified() {
+        for udp in [false, true] {
+            let network = SimulatedNetwork::new()
+                .with_socket(
+                    simulated_socket(1, 22),
+                    SimulatedSocket::open()
+                        .with_latency(Duration::from_millis(10))
+                        .with_banner(b"SSH-2.0-test"),
+                )
+                .with_socket(simulated_socket(1, 25), SimulatedSocket::filtered());
+            let scanner = simulated_scanner(&network, vec![22, 25, 80], 10, 2, udp)
+                .with_banner_grab(BannerGrab::default())
+                .build();
+            let report = block_on(scanner.run());
+
+            let result_of = |socket| {
+                report
+                    .results
+                    .iter()
+                    .find(|result| result.socket == socket)
+                    .unwrap()
+                    .clone()
+            };
+            let open = result_of(simulated_socket(1, 22));
+            assert_eq!(open.state, PortState::Open);
+            assert!(open.rtt.unwrap() >= Duration::from_millis(10));
+            let silent = if udp {
+                PortState::OpenFiltered
+            } else {
+                assert_eq!(open.banner.unwrap().data, b"SSH-2.0-test");
+                PortState::Filtered
+            };
+            assert_eq!(result_of(simulated_socket(1, 25)).state, silent);
+            assert_eq!(result_of(simulated_socket(1, 80)).state, PortState::Closed);
+            assert_eq!(result_of(simulated_socket(2, 22)).state, PortState::Closed);
+        }
+    }
+
+    #[test]
+    fn report_keeps_the_open_sockets() {
+        let network =
+            SimulatedNetwork::new().with_socket(simulated_socket(1, 22), SimulatedSocket::open());
+        let scanner = simulated_scanner(&network, vec![22, 23], 10, 1, false)
+            .with_all_results(false)
+            .build();
+        let report = block_on(scanner.run());
+
+        let sockets: Vec<SocketAddr> = report.results.iter().map(|result| result.socket).collect();
+        assert_eq!(sockets, [simulated_socket(1, 22)]);
+        assert_eq!(report.summary.open, 1);
+        assert_eq!(report.summary.closed, 3);
+    }
+
+    #[test]
+    fn lost_probes_are_retried() {
+        let network = SimulatedNetwork::new()
+            .with_socket(simulated_socket(1, 22), SimulatedSocket::open().losing(2))
+            .with_socket(simulated_socket(1, 23), SimulatedSocket::open().losing(3));
+        let report = block_on(
+            simulated_scanner(&network, vec![22, 23], 10, 3, false)
+                .build()
+                .run(),
+        );
+
+        let probes_to = |socket| {
+            network
+                .probes()
+                .iter()
+                .filter(|probe| probe.socket == socket)
+                .count()
+        };
+        assert_eq!(probes_to(simulated_socket(1, 22)), 3);
+        assert_eq!(probes_to(simulated_socket(1, 23)), 3);
+        // A refusal is an answer, it is not tried again.
+        assert_eq!(probes_to(simulated_socket(2, 22)), 1);
+        let state_of = |socket| {
+            report
+                .results
+                .iter()
+                .find(|result| result.socket == socket)
+                .map(|result| result.state)
+        };
+        assert_eq!(state_of(simulated_socket(1, 22)), Some(PortState::Open));
+        assert_eq!(state_of(simulated_socket(1, 23)), Some(PortState::Filtered));
+    }
+
+    #[test]
+    fn shuffled_scans_finish_every_host_once() {
+        let network =
+            SimulatedNetwork::new().with_socket(simulated_socket(2, 7), SimulatedSocket::open());
+        let ports: Vec<u16> = (1..=20).collect();
+        let scanner = simulated_scanner(&network, ports, 4, 1, false)
+            .with_shuffled_sockets(3)
+            .build();
+        let mut finished = Vec::new();
+        block_on(scanner.run_with(|event| {
+            if let ScanEvent::HostFinished { ip, open_ports, .. } = event {
+                finished.push((ip, open_ports));
+            }
+        }));
+
+        finished.sort();
+        assert_eq!(
+            finished,
+            [
+                (simulated_socket(1, 0).ip(), 0),
+                (simulated_socket(2, 0).ip(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn silent_hosts_are_skipped() {
+        // The first host refuses one early port, the second never answers.
+        let network = SimulatedNetwork::new()
+            .with_default(SimulatedSocket::filtered())
+            .with_socket(simulated_socket(1, 2), SimulatedSocket::closed());
+        let ports: Vec<u16> = (1..=8).collect();
+        let scanner = simulated_scanner(&network, ports.clone(), 1, 1, false)
+            .with_dead_host_threshold(3)
+            .build();
+        let mut skipped = Vec::new();
+        let report = block_on(scanner.run_with(|event| {
+            if let ScanEvent::HostSkipped { ip, reason } = event {
+                skipped.push((ip, reason));
+            }
+        }));
+
+        let dead = simulated_socket(2, 0).ip();
+        let reason = SkipReason::Unresponsive { probes: 3 };
+        assert_eq!(skipped, [(dead, reason)]);
+        assert_eq!(report.skipped_hosts, HashMap::from([(dead, reason)]));
+        let probes_to = |network: &SimulatedNetwork, ip| {
+            network
+                .probes()
+                .iter()
+                .filter(|probe| probe.socket.ip() == ip)
+                .count()
+        };
+        assert_eq!(probes_to(&network, dead), 3);
+        assert_eq!(probes_to(&network, simulated_socket(1, 0).ip()), 8);
+        assert_eq!(report.summary.unscanned, 5);
+
+        // A host known to be up is scanned to the end all the same.
+        let network = SimulatedNetwork::new().with_default(SimulatedSocket::filtered());
+        let report = block_on(
+            simulated_scanner(&network, ports, 1, 1, false)
+                .with_dead_host_threshold(3)
+                .with_live_hosts(&[dead])
+                .build()
+                .run(),
+        );
+        assert_eq!(report.skipped_hosts.len(), 1);
+        assert!(!report.skipped_hosts.contains_key(&dead));
+        assert_eq!(probes_to(&network, dead), 8);
+    }
+
+    #[test]
+    fn one_at_a_time_follows_the_scan_order() {
+        let network = SimulatedNetwork::new();
+        block_on(
+            simulated_scanner(&network, vec![443, 22], 1, 1, false)
+                .build()
+                .run(),
+        );
+
+        let order: Vec<SocketAddr> = network.probes().iter().map(|probe| probe.socket).collect();
+        assert_eq!(
+            order,
+            vec![
+                simulated_socket(1, 443),
+                simulated_socket(2, 443),
+                simulated_socket(1, 22),
+                simulated_socket(2, 22),
+            ]
+        );
+    }
+
+    #[test]
+    fn max_rate_spaces_simulated_probes() {
+        let network = SimulatedNetwork::new();
+        let scanner = simulated_scanner(&network, vec![1, 2, 3, 4], 10, 1, false)
+            .with_max_rate(50)
+            .build();
+        block_on(scanner.run());
+
+        let probes = network.probes();
+        assert_eq!(probes.len(), 8);
+        // 50 per second is one slot every 20ms. A probe that wakes up late
+        // may be followed closely by the next, but none goes before its slot.
+        for (slot, probe) in probes.iter().enumerate() {
+            let earliest = Duration::from_millis(20) * slot as u32;
+            assert!(probe.sent - probes[0].sent >= earliest, "probe {slot}");
+        }
+    }
 }
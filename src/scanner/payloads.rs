@@ -0,0 +1,346 @@
+//! Datagrams sent to UDP ports to make the services behind them answer.
+//!
+//! Payloads come in the nmap-payloads format: an entry is `udp` followed
+//! by a port list and one or more quoted strings, which may span several
+//! lines. Keywords after the strings, like `source`, are ignored.
+use crate::service::unescape;
+use anyhow::{anyhow, bail, Result};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+static BUILTIN: Lazy<UdpPayloads> = Lazy::new(|| {
+    UdpPayloads::parse(include_str!("../../nmap-payloads"))
+        .expect("the embedded UDP payloads are valid")
+});
+
+/// One payload, named after the comment above it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UdpPayload {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+/// UDP payloads indexed by port.
+///
+/// A port may have several payloads, they are all sent at once. Ports
+/// without any get the fallback, an empty datagram unless changed with
+/// `with_fallback`.
+#[derive(Debug, Clone)]
+pub struct UdpPayloads {
+    by_port: HashMap<u16, Vec<Arc<UdpPayload>>>,
+    fallback: Arc<UdpPayload>,
+}
+
+impl UdpPayloads {
+    pub fn new(entries: impl IntoIterator<Item = (Vec<u16>, UdpPayload)>) -> Self {
+        let mut payloads = Self {
+            by_port: HashMap::new(),
+            fallback: Arc::new(UdpPayload {
+                name: "empty".to_string(),
+                data: Vec::new(),
+            }),
+        };
+        for (ports, payload) in entries {
+            payloads.insert(&ports, &Arc::new(payload));
+        }
+        payloads
+    }
+
+    /// The payloads built into the binary, from `nmap-payloads` at the root
+    /// of the repository.
+    pub fn builtin() -> &'static Self {
+        &BUILTIN
+    }
+
+    /// Loads a file such as `/usr/share/nmap/nmap-payloads`.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let data = fs::read(path)?;
+        Self::parse(&String::from_utf8_lossy(&data))
+    }
+
+    /// Parses payloads in the nmap-payloads format. Errors tell the line
+    /// they were found on.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut entries = Vec::new();
+        let mut entry: Option<Entry> = None;
+        // The first line of the comment right above an entry names it.
+        let mut comment: Option<String> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_nr = index + 1;
+            let tokens = tokenize(line).map_err(|e| anyhow!("line {line_nr}: {e}"))?;
+            match tokens.first() {
+                None if line.trim().is_empty() => comment = None,
+                None => {
+                    if comment.is_none() {
+                        let text = line.trim().trim_start_matches('#').trim();
+                        // Keep the first sentence, "GenericLines. Use for the echo service."
+                        comment = text.split(". ").next().map(ToString::to_string);
+                    }
+                }
+                Some(Token::Word("udp")) => {
+                    entries.extend(finish(entry.take())?);
+                    let ports = match tokens.get(1) {
+                        Some(Token::Word(ports)) => parse_ports(ports),
+                        _ => Err(anyhow!("missing port list")),
+                    }
+                    .map_err(|e| anyhow!("line {line_nr}: {e}"))?;
+                    let mut new = Entry {
+                        line_nr,
+                        name: comment.take().unwrap_or_else(|| format!("line {line_nr}")),
+                        ports,
+                        data: Vec::new(),
+                        strings: 0,
+                    };
+                    new.push(&tokens[2..]);
+                    entry = Some(new);
+                }
+                Some(_) => match &mut entry {
+                    Some(entry) => entry.push(&tokens),
+                    None => bail!("line {line_nr}: expected an entry starting with udp"),
+                },
+            }
+        }
+        entries.extend(finish(entry)?);
+        Ok(Self::new(entries))
+    }
+
+    /// Sends `payload` to the ports no other payload is registered for.
+    pub fn with_fallback(mut self, payload: UdpPayload) -> Self {
+        self.fallback = Arc::new(payload);
+        self
+    }
+
+    /// Adds the payloads of `other`. The ones already registered for a
+    /// port, like those of a newer nmap-payloads, are not sent twice.
+    pub fn merge(mut self, other: &UdpPayloads) -> Self {
+        for (port, payloads) in &other.by_port {
+            for payload in payloads {
+                self.insert(&[*port], payload);
+            }
+        }
+        self
+    }
+
+    /// Every payload to send to `port`, never empty.
+    pub fn for_port(&self, port: u16) -> &[Arc<UdpPayload>] {
+        self.by_port
+            .get(&port)
+            .map_or(std::slice::from_ref(&self.fallback), Vec::as_slice)
+    }
+
+    fn insert(&mut self, ports: &[u16], payload: &Arc<UdpPayload>) {
+        for port in ports {
+            let known = self.by_port.entry(*port).or_default();
+            if !known.iter().any(|other| other.data == payload.data) {
+                known.push(Arc::clone(payload));
+            }
+        }
+    }
+}
+
+impl Default for UdpPayloads {
+    fn default() -> Self {
+        Self::builtin().clone()
+    }
+}
+
+/// An entry while its lines are read.
+struct Entry {
+    line_nr: usize,
+    name: String,
+    ports: Vec<u16>,
+    data: Vec<u8>,
+    strings: usize,
+}
+
+impl Entry {
+    /// Strings add to the payload. Anything else is a keyword whose
+    /// arguments are on the same line, so the rest of the line is skipped.
+    fn push(&mut self, tokens: &[Token]) {
+        for token in tokens {
+            match token {
+                Token::String(bytes) => {
+                    self.data.extend_from_slice(bytes);
+                    self.strings += 1;
+                }
+                Token::Word(_) => break,
+            }
+        }
+    }
+}
+
+fn finish(entry: Option<Entry>) -> Result<Option<(Vec<u16>, UdpPayload)>> {
+    let Some(entry) = entry else {
+        return Ok(None);
+    };
+    if entry.strings == 0 {
+        bail!("line {}: entry without a payload", entry.line_nr);
+    }
+    Ok(Some((
+        entry.ports,
+        UdpPayload {
+            name: entry.name,
+            data: entry.data,
+        },
+    )))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Word(&'a str),
+    String(Vec<u8>),
+}
+
+/// Splits a line into words and quoted strings, up to a `#` comment.
+fn tokenize(line: &str) -> Result<Vec<Token<'_>>> {
+    let mut tokens = Vec::new();
+    let mut rest = line.trim_start();
+    while !rest.is_empty() && !rest.starts_with('#') {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let end = closing_quote(quoted).ok_or_else(|| anyhow!("unterminated string"))?;
+            tokens.push(Token::String(unescape(&quoted[..end])?));
+            rest = &quoted[end + 1..];
+        } else {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            tokens.push(Token::Word(&rest[..end]));
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+    }
+    Ok(tokens)
+}
+
+/// Index of the first `"` not escaped with a backslash.
+fn closing_quote(quoted: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (index, c) in quoted.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parses `111,2049,32768-65535`. Ranges include both ends.
+fn parse_ports(list: &str) -> Result<Vec<u16>> {
+    let mut ports = Vec::new();
+    for part in list.split(',').filter(|part| !part.is_empty()) {
+        let bad = || anyhow!("bad port {part:?}");
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let start: u16 = start.parse().map_err(|_| bad())?;
+                let end: u16 = end.parse().map_err(|_| bad())?;
+                ports.extend(start..=end);
+            }
+            None => ports.push(part.parse().map_err(|_| bad())?),
+        }
+    }
+    if ports.is_empty() {
+        bail!("empty port list");
+    }
+    Ok(ports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UdpPayload, UdpPayloads};
+
+    fn names(payloads: &UdpPayloads, port: u16) -> Vec<&str> {
+        payloads
+            .for_port(port)
+            .iter()
+            .map(|payload| payload.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn builtin_keeps_every_payload_of_a_port() {
+        let payloads = UdpPayloads::builtin();
+        assert_eq!(names(payloads, 53), ["DNSStatusRequest", "DNS VER"]);
+        assert_eq!(payloads.for_port(123).len(), 2);
+        assert_eq!(payloads.for_port(500).len(), 2);
+        assert_eq!(
+            payloads.for_port(69)[1].data,
+            b"\x00\x01r7tftp.txt\x00octet\x00"
+        );
+        // Ranges include their last port.
+        assert_eq!(names(payloads, 65535), ["ONCRPC CALL"]);
+    }
+
+    #[test]
+    fn entries_span_lines_and_skip_keywords() {
+        let payloads = UdpPayloads::parse(
+            "# In-house. Answers anything.\n\
+             udp 9999,10000-10001\n\
+             \x20 \"\\x01\\x02 # not a comment\"\n\
+             \x20 \"\\\"end\" # trailing comment\n\
+             source 500\n\
+             \n\
+             udp 7 \"\\r\\n\"\n",
+        )
+        .unwrap();
+        assert_eq!(names(&payloads, 10001), ["In-house"]);
+        assert_eq!(
+            payloads.for_port(9999)[0].data,
+            b"\x01\x02 # not a comment\"end"
+        );
+        assert_eq!(names(&payloads, 7), ["line 7"]);
+    }
+
+    #[test]
+    fn broken_lines_report_their_number() {
+        let cases = [
+            (
+                "udp 7 \"\\r\\n\"\nudp 8\n",
+                "line 2: entry without a payload",
+            ),
+            ("# x\n\nudp 7 \"abc\n", "line 3: unterminated string"),
+            ("udp 7,x \"a\"\n", "line 1: bad port \"x\""),
+            ("\"a\"\n", "line 1: expected an entry starting with udp"),
+        ];
+        for (text, message) in cases {
+            let error = UdpPayloads::parse(text).unwrap_err();
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn merged_payloads_are_not_sent_twice() {
+        let echo = |name: &str| UdpPayload {
+            name: name.to_string(),
+            data: b"\r\n".to_vec(),
+        };
+        let payloads = UdpPayloads::new([(vec![7], echo("echo"))])
+            .merge(&UdpPayloads::new([(vec![7, 8], echo("copy"))]));
+        assert_eq!(names(&payloads, 7), ["echo"]);
+        assert_eq!(names(&payloads, 8), ["copy"]);
+    }
+
+    #[test]
+    fn unknown_ports_get_the_fallback() {
+        let payloads = UdpPayloads::new([(
+            vec![7],
+            UdpPayload {
+                name: "echo".to_string(),
+                data: b"\r\n".to_vec(),
+            },
+        )]);
+        assert_eq!(names(&payloads, 7), ["echo"]);
+        assert_eq!(names(&payloads, 8), ["empty"]);
+        assert!(payloads.for_port(8)[0].data.is_empty());
+
+        let payloads = payloads.with_fallback(UdpPayload {
+            name: "nul".to_string(),
+            data: vec![0],
+        });
+        assert_eq!(payloads.for_port(8)[0].data, [0]);
+        assert_eq!(names(&payloads, 7), ["echo"]);
+    }
+}
@@ -0,0 +1,106 @@
+//! A seeded shuffle of `0..size` that never holds the shuffled list.
+//!
+//! `RangeIterator` walks ports with a random coprime step, which is cheap
+//! but keeps a fixed stride between two picks. Over the whole IP and port
+//! space that stride shows: the same port comes up again after a regular
+//! number of hosts. A small Feistel network gives an order without such
+//! a pattern and still maps any position to its value in constant time,
+//! which is all shards and checkpoints need.
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const ROUNDS: usize = 4;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Permutation {
+    size: u64,
+    /// The network works on numbers of twice this many bits, the smallest
+    /// that still holds every value below `size`.
+    half_bits: u32,
+    keys: [u64; ROUNDS],
+}
+
+impl Permutation {
+    /// The same size and seed always give the same order, whatever the
+    /// platform or build, so a shard or a checkpoint can be picked up by
+    /// another binary.
+    pub(crate) fn with_seed(size: u64, seed: u64) -> Self {
+        let bits = u64::BITS - size.saturating_sub(1).leading_zeros();
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        Self {
+            size,
+            half_bits: bits.div_ceil(2).max(1),
+            keys: rng.gen(),
+        }
+    }
+
+    /// The value at `position`, which must be below the size.
+    pub(crate) fn get(&self, position: u64) -> u64 {
+        debug_assert!(position < self.size);
+        // The network shuffles a power of two at least as large as the
+        // size. Going through it again until we land back in range keeps
+        // it a shuffle of `0..size`, and takes less than four turns on
+        // average.
+        let mut value = position;
+        loop {
+            value = self.encrypt(value);
+            if value < self.size {
+                return value;
+            }
+        }
+    }
+
+    fn encrypt(&self, value: u64) -> u64 {
+        let mask = (1 << self.half_bits) - 1;
+        let (mut left, mut right) = (value >> self.half_bits, value & mask);
+        for key in self.keys {
+            (left, right) = (right, left ^ (mix(right ^ key) & mask));
+        }
+        (left << self.half_bits) | right
+    }
+}
+
+/// The splitmix64 finalizer, any bit of the input flips half of the
+/// output.
+fn mix(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Permutation;
+
+    #[test]
+    fn every_value_comes_up_once() {
+        for size in [1, 2, 3, 17, 1000, 65_535 * 3] {
+            let permutation = Permutation::with_seed(size, 7);
+            let mut values: Vec<u64> = (0..size)
+                .map(|position| permutation.get(position))
+                .collect();
+            values.sort_unstable();
+            assert!(values.iter().copied().eq(0..size), "size {size}");
+        }
+    }
+
+    #[test]
+    fn the_seed_picks_the_order() {
+        let order = |seed| -> Vec<u64> {
+            let permutation = Permutation::with_seed(1000, seed);
+            (0..1000)
+                .map(|position| permutation.get(position))
+                .collect()
+        };
+        assert_eq!(order(1), order(1));
+        assert_ne!(order(1), order(2));
+        assert_ne!(order(1), (0..1000).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn seed_pins_the_permutation() {
+        let permutation = Permutation::with_seed(10, 42);
+        let order: Vec<u64> = (0..10).map(|position| permutation.get(position)).collect();
+        assert_eq!(order, vec![4, 2, 1, 6, 7, 0, 5, 3, 9, 8]);
+    }
+}
@@ -0,0 +1,179 @@
+//! Per-socket outcomes produced by the scanner.
+use super::{Banner, ScanSummary, Shard, SkipReason};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+/// The state of a single scanned socket.
+///
+/// The state is decided from what actually happened on the wire, following
+/// nmap's vocabulary:
+///   - Open: the connection was accepted (TCP) or the service answered (UDP).
+///   - Closed: the host answered with a refusal (TCP RST / ECONNREFUSED).
+///   - Filtered: nothing came back before the timeout, so a firewall most
+///     likely dropped the probe silently.
+///   - OpenFiltered: UDP only, no answer at all, which can mean either an open
+///     service that ignored our payload or a firewall drop.
+///   - Unreachable: the host or network was reported as unreachable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PortState {
+    Open,
+    Closed,
+    Filtered,
+    OpenFiltered,
+    Unreachable,
+}
+
+impl PortState {
+    /// Classifies a connect/send error into a port state.
+    ///
+    /// Returns `None` for errors that say nothing about the target, such as
+    /// running out of file descriptors or a local firewall rule denying the
+    /// connect, which is what `PermissionDenied` means here.
+    pub fn from_error(error: &io::Error) -> Option<Self> {
+        match error.kind() {
+            io::ErrorKind::ConnectionRefused | io::ErrorKind::ConnectionReset => {
+                Some(PortState::Closed)
+            }
+            io::ErrorKind::TimedOut => Some(PortState::Filtered),
+            io::ErrorKind::HostUnreachable | io::ErrorKind::NetworkUnreachable => {
+                Some(PortState::Unreachable)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_open(self) -> bool {
+        self == PortState::Open
+    }
+}
+
+impl fmt::Display for PortState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            PortState::Open => "open",
+            PortState::Closed => "closed",
+            PortState::Filtered => "filtered",
+            PortState::OpenFiltered => "open|filtered",
+            PortState::Unreachable => "unreachable",
+        };
+        f.write_str(label)
+    }
+}
+
+/// The outcome of scanning one socket.
+///
+/// `rtt` is the time it took the target to answer, it is only known when
+/// something actually came back (open or closed). `banner` is only set for
+/// open TCP sockets, when the scanner was asked to grab banners.
+/// `udp_payload` names the payload an open UDP socket answered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanResult {
+    pub socket: SocketAddr,
+    pub state: PortState,
+    pub rtt: Option<Duration>,
+    pub banner: Option<Banner>,
+    pub udp_payload: Option<String>,
+}
+
+impl ScanResult {
+    pub fn new(socket: SocketAddr, state: PortState) -> Self {
+        Self {
+            socket,
+            state,
+            rtt: None,
+            banner: None,
+            udp_payload: None,
+        }
+    }
+
+    pub fn with_rtt(mut self, rtt: Duration) -> Self {
+        self.rtt = Some(rtt);
+        self
+    }
+
+    pub fn with_banner(mut self, banner: Option<Banner>) -> Self {
+        self.banner = banner;
+        self
+    }
+
+    pub fn with_udp_payload(mut self, name: &str) -> Self {
+        self.udp_payload = Some(name.to_string());
+        self
+    }
+}
+
+/// Everything a finished (or stopped) scan produced.
+///
+/// `results` only holds the open sockets unless the scan was set up with
+/// `ScannerBuilder::with_all_results`, the summary counts every state.
+/// `host_rtts` holds the smoothed round-trip time of every host that answered.
+#[derive(Debug, Clone, Default)]
+pub struct ScanReport {
+    pub results: Vec<ScanResult>,
+    pub summary: ScanSummary,
+    pub host_rtts: HashMap<IpAddr, Duration>,
+    /// Hosts whose remaining sockets were left out, and why.
+    pub skipped_hosts: HashMap<IpAddr, SkipReason>,
+    /// Set when this is one part of a sharded scan, to merge the parts.
+    pub shard: Option<Shard>,
+}
+
+impl ScanReport {
+    /// False when the scan was cancelled or ran out of time, in which case
+    /// `results` only holds what was found up to that point.
+    pub fn is_complete(&self) -> bool {
+        self.summary.is_complete()
+    }
+
+    pub fn open_sockets(&self) -> impl Iterator<Item = SocketAddr> + '_ {
+        self.results
+            .iter()
+            .filter(|result| result.state.is_open())
+            .map(|result| result.socket)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PortState;
+    use std::io;
+
+    #[test]
+    fn refused_is_closed() {
+        let error = io::Error::from(io::ErrorKind::ConnectionRefused);
+        assert_eq!(PortState::from_error(&error), Some(PortState::Closed));
+    }
+
+    #[test]
+    fn timeout_is_filtered() {
+        let error = io::Error::from(io::ErrorKind::TimedOut);
+        assert_eq!(PortState::from_error(&error), Some(PortState::Filtered));
+    }
+
+    #[test]
+    fn host_unreachable_has_its_own_state() {
+        let error = io::Error::from(io::ErrorKind::HostUnreachable);
+        assert_eq!(PortState::from_error(&error), Some(PortState::Unreachable));
+    }
+
+    #[test]
+    fn local_errors_are_not_classified() {
+        let error = io::Error::other("Too many open files");
+        assert_eq!(PortState::from_error(&error), None);
+        // Out of ephemeral ports, the target has nothing to do with it.
+        let error = io::Error::from(io::ErrorKind::AddrNotAvailable);
+        assert_eq!(PortState::from_error(&error), None);
+        // EPERM or EACCES, a rule on this host and not the target's answer.
+        let error = io::Error::from(io::ErrorKind::PermissionDenied);
+        assert_eq!(PortState::from_error(&error), None);
+    }
+
+    #[test]
+    fn display_uses_nmap_labels() {
+        assert_eq!(PortState::OpenFiltered.to_string(), "open|filtered");
+    }
+}
@@ -0,0 +1,132 @@
+//! Round-trip time estimation and the per-host timeouts derived from it.
+//!
+//! This is the estimator from RFC 6298, which nmap uses as well: a smoothed
+//! RTT plus four times its mean deviation makes a timeout that follows the
+//! host without cutting off its slower answers.
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::Duration;
+
+/// Smoothed round-trip time of a single host.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RttEstimator {
+    srtt: Option<Duration>,
+    rttvar: Duration,
+}
+
+impl RttEstimator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, rtt: Duration) {
+        match self.srtt {
+            None => {
+                self.srtt = Some(rtt);
+                self.rttvar = rtt / 2;
+            }
+            Some(srtt) => {
+                // rttvar = 3/4 rttvar + 1/4 |srtt - rtt|, srtt = 7/8 srtt + 1/8 rtt
+                self.rttvar = (self.rttvar * 3 + srtt.abs_diff(rtt)) / 4;
+                self.srtt = Some((srtt * 7 + rtt) / 8);
+            }
+        }
+    }
+
+    /// The smoothed RTT, once at least one sample was recorded.
+    pub fn srtt(&self) -> Option<Duration> {
+        self.srtt
+    }
+
+    pub fn rttvar(&self) -> Duration {
+        self.rttvar
+    }
+
+    /// How long to wait for this host, once at least one sample was recorded.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.srtt.map(|srtt| srtt + self.rttvar * 4)
+    }
+}
+
+/// RTT estimators for every host of a scan.
+#[derive(Debug, Default)]
+pub(crate) struct HostRtts {
+    hosts: HashMap<IpAddr, RttEstimator>,
+}
+
+impl HostRtts {
+    pub(crate) fn record(&mut self, ip: IpAddr, rtt: Duration) {
+        self.hosts.entry(ip).or_default().record(rtt);
+    }
+
+    pub(crate) fn srtt(&self, ip: IpAddr) -> Option<Duration> {
+        self.hosts.get(&ip).and_then(RttEstimator::srtt)
+    }
+
+    /// The timeout for `ip` clamped to `min..=max`, or `initial` until the
+    /// host has answered at least once.
+    pub(crate) fn timeout(
+        &self,
+        ip: IpAddr,
+        initial: Duration,
+        min: Duration,
+        max: Duration,
+    ) -> Duration {
+        self.hosts
+            .get(&ip)
+            .and_then(RttEstimator::timeout)
+            .map_or(initial, |timeout| timeout.clamp(min, max))
+    }
+
+    pub(crate) fn srtts(&self) -> HashMap<IpAddr, Duration> {
+        self.hosts
+            .iter()
+            .filter_map(|(ip, estimator)| Some((*ip, estimator.srtt()?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HostRtts, RttEstimator};
+    use std::net::IpAddr;
+    use std::time::Duration;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn first_sample_sets_srtt_and_half_variance() {
+        let mut estimator = RttEstimator::new();
+        assert_eq!(estimator.timeout(), None);
+
+        estimator.record(MS * 100);
+        assert_eq!(estimator.srtt(), Some(MS * 100));
+        assert_eq!(estimator.rttvar(), MS * 50);
+        assert_eq!(estimator.timeout(), Some(MS * 300));
+    }
+
+    #[test]
+    fn steady_samples_shrink_the_variance() {
+        let mut estimator = RttEstimator::new();
+        for _ in 0..50 {
+            estimator.record(MS * 20);
+        }
+        assert_eq!(estimator.srtt(), Some(MS * 20));
+        assert!(estimator.timeout().unwrap() < MS * 21);
+    }
+
+    #[test]
+    fn timeouts_are_per_host_and_bounded() {
+        let near: IpAddr = "10.0.0.1".parse().unwrap();
+        let far: IpAddr = "10.0.0.2".parse().unwrap();
+        let unknown: IpAddr = "10.0.0.3".parse().unwrap();
+        let mut rtts = HostRtts::default();
+        rtts.record(near, MS);
+        rtts.record(far, MS * 4_000);
+
+        let timeout = |ip| rtts.timeout(ip, MS * 1_500, MS * 100, MS * 10_000);
+        assert_eq!(timeout(near), MS * 100);
+        assert_eq!(timeout(far), MS * 10_000);
+        assert_eq!(timeout(unknown), MS * 1_500);
+    }
+}
@@ -0,0 +1,87 @@
+//! Splitting one scan between several workers.
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// One part of a scan split `count` ways. Sockets are dealt out in scan
+/// order, the `index`th worker takes every `count`th one, so workers that
+/// share targets, ports and seed never scan the same socket twice and
+/// together leave none out.
+///
+/// `index` counts from 1, like on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Shard {
+    pub index: u32,
+    pub count: u32,
+}
+
+impl Shard {
+    /// The whole scan, in one part.
+    pub const WHOLE: Shard = Shard { index: 1, count: 1 };
+
+    /// Whether the socket at `position` of the scan order is ours.
+    pub fn owns(self, position: usize) -> bool {
+        position % self.stride() == self.offset()
+    }
+
+    /// How far apart our sockets are.
+    pub(crate) fn stride(self) -> usize {
+        self.count as usize
+    }
+
+    /// Position of our first socket.
+    pub(crate) fn offset(self) -> usize {
+        (self.index - 1) as usize
+    }
+}
+
+impl Default for Shard {
+    fn default() -> Self {
+        Self::WHOLE
+    }
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (index, count) = input
+            .split_once('/')
+            .ok_or_else(|| format!("expected a shard like 1/4, got {input:?}"))?;
+        let index: u32 = index
+            .trim()
+            .parse()
+            .map_err(|_| format!("bad shard number {index:?}"))?;
+        let count: u32 = count
+            .trim()
+            .parse()
+            .map_err(|_| format!("bad shard count {count:?}"))?;
+        if index == 0 || index > count {
+            return Err(format!("shard number must be between 1 and {count}"));
+        }
+        Ok(Self { index, count })
+    }
+}
+
+impl fmt::Display for Shard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Shard;
+
+    #[test]
+    fn parses_like_it_prints() {
+        let shard: Shard = "2/5".parse().unwrap();
+        assert_eq!(shard, Shard { index: 2, count: 5 });
+        assert_eq!(shard.to_string(), "2/5");
+        assert!(shard.owns(1) && shard.owns(6) && !shard.owns(2));
+
+        for bad in ["0/3", "4/3", "1/0", "3", "a/b"] {
+            assert!(bad.parse::<Shard>().is_err(), "{bad}");
+        }
+    }
+}
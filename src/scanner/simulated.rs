@@ -0,0 +1,319 @@
+//! A network that only exists in memory, for testing what the scanner
+//! makes of the answers it gets.
+//!
+//! Every socket is declared open, closed or filtered, with a latency and a
+//! number of probes that get lost on the way. Nothing is random, the same
+//! scan always sees the same network, and every probe is written down so
+//! tests can check the order, the retries and the pacing.
+use super::{IcmpEvent, Transport, UdpReply};
+use async_std::task;
+use futures::future::{self, BoxFuture};
+use futures::{AsyncRead, AsyncWrite, FutureExt};
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Behaviour {
+    Open,
+    #[default]
+    Closed,
+    Filtered,
+}
+
+/// How a socket of a `SimulatedNetwork` answers, over TCP and UDP alike.
+///
+/// An open UDP port echoes the payload back, a closed one answers with
+/// ICMP port unreachable and a filtered one never answers.
+#[derive(Debug, Clone, Default)]
+pub struct SimulatedSocket {
+    behaviour: Behaviour,
+    latency: Duration,
+    lost: usize,
+    banner: Vec<u8>,
+}
+
+impl SimulatedSocket {
+    pub fn open() -> Self {
+        Self {
+            behaviour: Behaviour::Open,
+            ..Self::default()
+        }
+    }
+
+    pub fn closed() -> Self {
+        Self::default()
+    }
+
+    pub fn filtered() -> Self {
+        Self {
+            behaviour: Behaviour::Filtered,
+            ..Self::default()
+        }
+    }
+
+    /// Answers after `latency`.
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Loses the first `count` probes, the ones after get through.
+    pub fn losing(mut self, count: usize) -> Self {
+        self.lost = count;
+        self
+    }
+
+    /// Sends `banner` to whoever connects and then closes the connection.
+    pub fn with_banner(mut self, banner: &[u8]) -> Self {
+        self.banner = banner.to_vec();
+        self
+    }
+}
+
+/// A probe sent over a `SimulatedNetwork`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulatedProbe {
+    pub socket: SocketAddr,
+    pub sent: Instant,
+}
+
+/// An in-memory network, see the module docs. Sockets that were not
+/// declared are closed unless `with_default` says otherwise.
+///
+/// Clones share the network, so a test can keep one to look at the probes
+/// while the scanner owns the other.
+#[derive(Debug, Clone, Default)]
+pub struct SimulatedNetwork {
+    state: Arc<Mutex<NetworkState>>,
+}
+
+#[derive(Debug, Default)]
+struct NetworkState {
+    sockets: HashMap<SocketAddr, SimulatedSocket>,
+    default: SimulatedSocket,
+    /// How many probes each socket has seen so far.
+    seen: HashMap<SocketAddr, usize>,
+    probes: Vec<SimulatedProbe>,
+}
+
+impl SimulatedNetwork {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_socket(self, socket: SocketAddr, simulated: SimulatedSocket) -> Self {
+        self.state.lock().unwrap().sockets.insert(socket, simulated);
+        self
+    }
+
+    /// How the sockets that were not declared answer.
+    pub fn with_default(self, simulated: SimulatedSocket) -> Self {
+        self.state.lock().unwrap().default = simulated;
+        self
+    }
+
+    /// Every probe sent so far, in the order they were sent.
+    pub fn probes(&self) -> Vec<SimulatedProbe> {
+        self.state.lock().unwrap().probes.clone()
+    }
+
+    /// Writes the probe down and tells how `socket` answers it, `None`
+    /// when it gets no answer at all.
+    fn probe(&self, socket: SocketAddr) -> Option<SimulatedSocket> {
+        let mut state = self.state.lock().unwrap();
+        state.probes.push(SimulatedProbe {
+            socket,
+            sent: Instant::now(),
+        });
+        let seen = state.seen.entry(socket).or_default();
+        *seen += 1;
+        let seen = *seen;
+        let simulated = state.sockets.get(&socket).unwrap_or(&state.default).clone();
+        (seen > simulated.lost && simulated.behaviour != Behaviour::Filtered).then_some(simulated)
+    }
+}
+
+/// A TCP connection to an open socket of a `SimulatedNetwork`. It reads
+/// the banner and then the end of the stream, writes go nowhere.
+#[derive(Debug)]
+pub struct SimulatedStream {
+    banner: Vec<u8>,
+    read: usize,
+}
+
+impl AsyncRead for SimulatedStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let rest = &self.banner[self.read..];
+        let size = rest.len().min(buf.len());
+        buf[..size].copy_from_slice(&rest[..size]);
+        self.read += size;
+        Poll::Ready(Ok(size))
+    }
+}
+
+impl AsyncWrite for SimulatedStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// An answer on its way to a `SimulatedUdpSocket`, with the time it
+/// arrives.
+type Delivery = (Instant, Result<Vec<u8>, IcmpEvent>);
+
+/// A UDP socket of a `SimulatedNetwork`.
+#[derive(Debug)]
+pub struct SimulatedUdpSocket {
+    target: SocketAddr,
+    inbox: Mutex<VecDeque<Delivery>>,
+}
+
+impl Transport for SimulatedNetwork {
+    type TcpStream = SimulatedStream;
+    type UdpSocket = SimulatedUdpSocket;
+
+    fn connect(&self, target: SocketAddr) -> BoxFuture<'_, io::Result<SimulatedStream>> {
+        async move {
+            let Some(simulated) = self.probe(target) else {
+                return future::pending().await;
+            };
+            task::sleep(simulated.latency).await;
+            match simulated.behaviour {
+                Behaviour::Open => Ok(SimulatedStream {
+                    banner: simulated.banner,
+                    read: 0,
+                }),
+                _ => Err(io::ErrorKind::ConnectionRefused.into()),
+            }
+        }
+        .boxed()
+    }
+
+    fn udp_socket(&self, target: SocketAddr) -> BoxFuture<'_, io::Result<SimulatedUdpSocket>> {
+        future::ready(Ok(SimulatedUdpSocket {
+            target,
+            inbox: Mutex::default(),
+        }))
+        .boxed()
+    }
+
+    fn send<'a>(
+        &'a self,
+        socket: &'a SimulatedUdpSocket,
+        payload: &'a [u8],
+    ) -> BoxFuture<'a, io::Result<()>> {
+        if let Some(simulated) = self.probe(socket.target) {
+            let answer = match simulated.behaviour {
+                Behaviour::Open => Ok(payload.to_vec()),
+                _ => Err(IcmpEvent::PortUnreachable),
+            };
+            let arrival = Instant::now() + simulated.latency;
+            socket.inbox.lock().unwrap().push_back((arrival, answer));
+        }
+        future::ready(Ok(())).boxed()
+    }
+
+    fn recv<'a>(
+        &'a self,
+        socket: &'a SimulatedUdpSocket,
+        buf: &'a mut [u8],
+    ) -> BoxFuture<'a, io::Result<UdpReply>> {
+        async move {
+            let Some(arrival) = socket.inbox.lock().unwrap().front().map(|(at, _)| *at) else {
+                return future::pending().await;
+            };
+            // Only taken once it arrived, a recv that times out before
+            // leaves it for the next one.
+            task::sleep(arrival.saturating_duration_since(Instant::now())).await;
+            let (_, answer) = socket.inbox.lock().unwrap().pop_front().unwrap();
+            Ok(match answer {
+                Ok(data) => {
+                    let size = data.len().min(buf.len());
+                    buf[..size].copy_from_slice(&data[..size]);
+                    UdpReply::Answer(size)
+                }
+                Err(event) => UdpReply::Icmp(event),
+            })
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SimulatedNetwork, SimulatedSocket};
+    use crate::scanner::{IcmpEvent, Transport, UdpReply};
+    use async_std::io;
+    use async_std::task::block_on;
+    use futures::AsyncReadExt;
+    use std::net::SocketAddr;
+    use std::time::Duration;
+
+    fn socket(port: u16) -> SocketAddr {
+        SocketAddr::from(([192, 0, 2, 1], port))
+    }
+
+    #[test]
+    fn sockets_answer_as_declared() {
+        let network = SimulatedNetwork::new()
+            .with_socket(socket(22), SimulatedSocket::open().with_banner(b"SSH-2.0"))
+            .with_socket(socket(25), SimulatedSocket::filtered());
+        let wait = Duration::from_millis(20);
+
+        let mut stream = block_on(network.connect(socket(22))).unwrap();
+        let mut banner = Vec::new();
+        block_on(stream.read_to_end(&mut banner)).unwrap();
+        assert_eq!(banner, b"SSH-2.0");
+
+        let refused = block_on(network.connect(socket(80))).err().unwrap();
+        assert_eq!(refused.kind(), std::io::ErrorKind::ConnectionRefused);
+        assert!(block_on(io::timeout(wait, network.connect(socket(25)))).is_err());
+
+        let udp = block_on(network.udp_socket(socket(80))).unwrap();
+        block_on(network.send(&udp, b"ping")).unwrap();
+        let reply = block_on(network.recv(&udp, &mut [0; 16])).unwrap();
+        assert_eq!(reply, UdpReply::Icmp(IcmpEvent::PortUnreachable));
+    }
+
+    #[test]
+    fn lost_probes_get_no_answer() {
+        let network = SimulatedNetwork::new().with_socket(
+            socket(53),
+            SimulatedSocket::open()
+                .losing(1)
+                .with_latency(Duration::from_millis(5)),
+        );
+        let udp = block_on(network.udp_socket(socket(53))).unwrap();
+        let mut buf = [0; 16];
+        let wait = Duration::from_millis(50);
+
+        block_on(network.send(&udp, b"first")).unwrap();
+        assert!(block_on(io::timeout(wait, network.recv(&udp, &mut buf))).is_err());
+        block_on(network.send(&udp, b"second")).unwrap();
+        let reply = block_on(io::timeout(wait, network.recv(&udp, &mut buf))).unwrap();
+        assert_eq!(reply, UdpReply::Answer(6));
+        assert_eq!(&buf[..6], b"second");
+        assert_eq!(network.probes().len(), 2);
+    }
+}
@@ -1,17 +1,22 @@
-use itertools::{iproduct, Product};
+use super::permutation::Permutation;
+use super::Shard;
+use gcd::Gcd;
 use std::net::{IpAddr, SocketAddr};
 
 pub struct SocketIterator<'s> {
-    // product_it is a cartesian product iterator over
-    // the slices of ports and IP addresses.
-    //
-    // The IP/port order is intentionally reversed here since we want
-    // the itertools::iproduct! macro below to generate the pairs with
-    // all the IPs for one port before moving on to the next one
-    // ("hold the port, go through all the IPs, then advance the port...").
+    ips: &'s [IpAddr],
+    ports: &'s [u16],
+    // Index of the next socket in the scan order. Every IP is paired with
+    // a port before moving on to the next port ("hold the port, go through
+    // all the IPs, then advance the port..."), so socket `n` is IP
+    // `n % ips.len()` on port `n / ips.len()`.
     // See also the comments in the iterator implementation for an example.
-    product_it:
-        Product<Box<std::slice::Iter<'s, u16>>, Box<std::slice::Iter<'s, std::net::IpAddr>>>,
+    position: usize,
+    // Only the sockets of this shard are handed out.
+    shard: Shard,
+    // When set, the socket at a position is picked through this shuffle
+    // of the whole IP and port space instead.
+    shuffle: Option<Permutation>,
 }
 
 /// An iterator that receives a slice of IPs and ports and returns a Socket
@@ -19,13 +24,92 @@ pub struct SocketIterator<'s> {
 /// The goal of this iterator is to go over every IP and port combination
 /// without generating a big memory footprint. The alternative would be
 /// generating a vector containing all these combinations.
+///
+/// Its only state is `position`, which is enough to pick a scan up again.
 impl<'s> SocketIterator<'s> {
+    #[cfg(test)]
     pub fn new(ips: &'s [IpAddr], ports: &'s [u16]) -> Self {
-        let ports_it = Box::new(ports.iter());
-        let ips_it = Box::new(ips.iter());
+        Self::starting_at(ips, ports, 0)
+    }
+
+    /// Skips the first `position` sockets of the scan order.
+    pub fn starting_at(ips: &'s [IpAddr], ports: &'s [u16], position: usize) -> Self {
         Self {
-            product_it: iproduct!(ports_it, ips_it),
+            ips,
+            ports,
+            position,
+            shard: Shard::WHOLE,
+            shuffle: None,
+        }
+    }
+
+    /// Only hands out the sockets of `shard`. Positions keep counting
+    /// every socket of the scan, so that all shards agree on them.
+    pub fn sharded(mut self, shard: Shard) -> Self {
+        self.shard = shard;
+        let behind =
+            (shard.offset() + shard.stride() - self.position % shard.stride()) % shard.stride();
+        self.position += behind;
+        self
+    }
+
+    /// Goes through every IP and port pair in an order only `seed` decides,
+    /// instead of holding each port for all the IPs.
+    pub fn shuffled(mut self, seed: u64) -> Self {
+        self.shuffle = Some(Permutation::with_seed(self.total() as u64, seed));
+        self
+    }
+
+    /// Index of the socket the next call to `next` returns.
+    pub fn next_index(&self) -> usize {
+        self.position
+    }
+
+    /// How many sockets are left in total.
+    pub fn remaining(&self) -> usize {
+        self.total()
+            .saturating_sub(self.position)
+            .div_ceil(self.shard.stride())
+    }
+
+    /// How many of the sockets left belong to each IP, or `None` once
+    /// shuffled. Which IPs the positions left map to is only known by
+    /// going through them, which costs as much as the scan itself.
+    pub fn remaining_by_ip(&self) -> Option<Vec<usize>> {
+        if self.shuffle.is_some() {
+            return None;
         }
+        Some(
+            (0..self.ips.len())
+                .map(|index| self.remaining_for(index))
+                .collect(),
+        )
+    }
+
+    /// How many of the sockets left belong to the `index`th IP, in scan
+    /// order. Does not hold for shuffled iterators.
+    pub fn remaining_for(&self, index: usize) -> usize {
+        let ips = self.ips.len();
+        if index >= ips {
+            return 0;
+        }
+        // Sockets of this IP are `index` apart by `ips`, ours by the
+        // stride of the shard. Both hold every `period` sockets from the
+        // first one they share, if they share any.
+        let stride = self.shard.stride();
+        let Some(first) = (0..stride)
+            .map(|turn| index + turn * ips)
+            .find(|&position| self.shard.owns(position))
+        else {
+            return 0;
+        };
+        let period = ips / ips.gcd(stride) * stride;
+        let before = |end: usize| end.saturating_sub(first).div_ceil(period);
+        before(ips * self.ports.len()).saturating_sub(before(self.position))
+    }
+
+    fn total(&self) -> usize {
+        self.ips.len() * self.ports.len()
     }
 }
 
@@ -44,15 +128,25 @@ impl Iterator for SocketIterator<'_> {
     /// it.next(); // 192.168.0.1:443
     /// it.next(); // None
     fn next(&mut self) -> Option<Self::Item> {
-        self.product_it
-            .next()
-            .map(|(port, ip)| SocketAddr::new(*ip, *port))
+        if self.position >= self.total() {
+            return None;
+        }
+        let index = match &self.shuffle {
+            Some(shuffle) => shuffle.get(self.position as u64) as usize,
+            None => self.position,
+        };
+        self.position += self.shard.stride();
+        let ips = self.ips.len();
+        Some(SocketAddr::new(
+            self.ips[index % ips],
+            self.ports[index / ips],
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::SocketIterator;
+    use super::{Shard, SocketIterator};
     use std::net::{IpAddr, SocketAddr};
 
     #[test]
@@ -72,4 +166,105 @@ mod tests {
         assert_eq!(Some(SocketAddr::new(addrs[1], ports[2])), it.next());
         assert_eq!(None, it.next());
     }
+
+    #[test]
+    fn picks_up_from_a_position() {
+        let addrs = vec![
+            "127.0.0.1".parse::<IpAddr>().unwrap(),
+            "192.168.0.1".parse::<IpAddr>().unwrap(),
+        ];
+        let ports: Vec<u16> = vec![22, 80, 443];
+        let mut it = SocketIterator::starting_at(&addrs, &ports, 3);
+
+        assert_eq!(it.remaining_for(0), 1);
+        assert_eq!(it.remaining_for(1), 2);
+        assert_eq!(Some(SocketAddr::new(addrs[1], ports[1])), it.next());
+        assert_eq!(it.next_index(), 4);
+        assert_eq!(it.remaining_for(1), 1);
+        assert_eq!(it.count(), 2);
+    }
+
+    #[test]
+    fn shards_split_the_sockets_between_them() {
+        let addrs = vec![
+            "127.0.0.1".parse::<IpAddr>().unwrap(),
+            "192.168.0.1".parse::<IpAddr>().unwrap(),
+            "192.168.0.2".parse::<IpAddr>().unwrap(),
+            "192.168.0.3".parse::<IpAddr>().unwrap(),
+        ];
+        let ports: Vec<u16> = (1..=25).collect();
+        let whole: Vec<SocketAddr> = SocketIterator::new(&addrs, &ports).collect();
+
+        let mut seen = Vec::new();
+        for index in 1..=6 {
+            let shard = Shard { index, count: 6 };
+            let it = SocketIterator::new(&addrs, &ports).sharded(shard);
+            let picked: Vec<SocketAddr> =
+                SocketIterator::new(&addrs, &ports).sharded(shard).collect();
+            assert_eq!(it.remaining(), picked.len());
+            for (ip, addr) in addrs.iter().enumerate() {
+                let count = picked.iter().filter(|socket| socket.ip() == *addr).count();
+                assert_eq!(it.remaining_for(ip), count);
+            }
+            seen.extend(picked);
+        }
+        seen.sort();
+        let mut expected = whole;
+        expected.sort();
+        assert_eq!(seen, expected);
+    }
+
+    #[test]
+    fn sharded_iterators_resume_on_their_own_sockets() {
+        let addrs = vec![
+            "127.0.0.1".parse::<IpAddr>().unwrap(),
+            "192.168.0.1".parse::<IpAddr>().unwrap(),
+        ];
+        let ports: Vec<u16> = vec![22, 80, 443, 8080];
+        let shard = Shard { index: 2, count: 3 };
+        let mut it = SocketIterator::starting_at(&addrs, &ports, 2).sharded(shard);
+
+        assert_eq!(it.next_index(), 4);
+        assert_eq!(it.remaining(), 2);
+        assert_eq!(it.remaining_by_ip(), Some(vec![1, 1]));
+        assert_eq!(Some(SocketAddr::new(addrs[0], ports[2])), it.next());
+        assert_eq!(Some(SocketAddr::new(addrs[1], ports[3])), it.next());
+        assert_eq!(None, it.next());
+    }
+
+    #[test]
+    fn shuffled_iterators_mix_ips_and_ports() {
+        let addrs: Vec<IpAddr> = (1..=20)
+            .map(|host| IpAddr::from([10, 0, 0, host]))
+            .collect();
+        let ports: Vec<u16> = (1..=50).collect();
+        let ordered: Vec<SocketAddr> = SocketIterator::new(&addrs, &ports).collect();
+        let shuffled: Vec<SocketAddr> = SocketIterator::new(&addrs, &ports).shuffled(9).collect();
+        assert_ne!(shuffled, ordered);
+        // The first sockets are not all on the same port any more.
+        assert!(shuffled[..20]
+            .iter()
+            .any(|socket| socket.port() != shuffled[0].port()));
+
+        let mut sorted = shuffled.clone();
+        sorted.sort();
+        let mut expected = ordered;
+        expected.sort();
+        assert_eq!(sorted, expected);
+
+        let shard = Shard { index: 1, count: 3 };
+        let it = SocketIterator::starting_at(&addrs, &ports, 30)
+            .sharded(shard)
+            .shuffled(9);
+        assert_eq!(it.remaining_by_ip(), None);
+        let left: Vec<SocketAddr> = it.collect();
+        assert_eq!(
+            left,
+            shuffled[30..]
+                .iter()
+                .step_by(3)
+                .copied()
+                .collect::<Vec<_>>()
+        );
+    }
 }
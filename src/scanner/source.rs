@@ -0,0 +1,240 @@
+//! Where probes leave from: a local address, an interface and source ports.
+//!
+//! Multi-homed hosts send from whatever address the routing table picks,
+//! which is not always the one firewalls let through.
+use async_io::Async;
+use async_std::net::{TcpStream, UdpSocket};
+use socket2::{Domain, Protocol, Socket, Type};
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// The local end of TCP and UDP probes. The default leaves everything to
+/// the OS.
+///
+/// Clones share the turn of the source ports.
+#[derive(Debug, Clone, Default)]
+pub struct Source {
+    ip: Option<IpAddr>,
+    interface: Option<String>,
+    /// Addresses of `interface`, looked up once.
+    interface_ips: Vec<IpAddr>,
+    ports: Option<RangeInclusive<u16>>,
+    next_port: Arc<AtomicUsize>,
+}
+
+impl Source {
+    /// Sends from `ip`. Targets of the other IP family are left to the OS.
+    pub fn with_ip(mut self, ip: IpAddr) -> Self {
+        self.ip = Some(ip);
+        self
+    }
+
+    /// Sends out of the interface called `name`, from its address unless
+    /// `with_ip` says otherwise. On Linux the sockets are also tied to the
+    /// interface when the process is allowed to.
+    pub fn with_interface(mut self, name: &str) -> io::Result<Self> {
+        self.interface_ips = if_addrs::get_if_addrs()?
+            .into_iter()
+            .filter(|interface| interface.name == name && !interface.is_link_local())
+            .map(|interface| interface.ip())
+            .collect();
+        if self.interface_ips.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no interface called {name} with an address"),
+            ));
+        }
+        self.interface = Some(name.to_owned());
+        Ok(self)
+    }
+
+    /// Sends from the ports of `ports`, one after the other. The ports are
+    /// shared between probes, so a single port works too.
+    pub fn with_ports(mut self, ports: RangeInclusive<u16>) -> Self {
+        self.ports = Some(ports);
+        self
+    }
+
+    /// The address `target` is probed from, before the OS fills in the
+    /// blanks.
+    pub fn local_addr(&self, target: SocketAddr) -> SocketAddr {
+        let same_family = |ip: &IpAddr| ip.is_ipv4() == target.is_ipv4();
+        let ip = self
+            .ip
+            .filter(same_family)
+            .or_else(|| self.interface_ips.iter().copied().find(same_family))
+            .unwrap_or(match target {
+                SocketAddr::V4(_) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+                SocketAddr::V6(_) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+            });
+        let port = self.ports.as_ref().map_or(0, |ports| {
+            let count = usize::from(ports.end() - ports.start()) + 1;
+            let turn = self.next_port.fetch_add(1, Ordering::Relaxed) % count;
+            ports.start() + turn as u16
+        });
+        SocketAddr::new(ip, port)
+    }
+
+    /// A TCP socket bound for `target`, not connected yet and non-blocking.
+    pub fn tcp_socket(&self, target: SocketAddr) -> io::Result<std::net::TcpStream> {
+        self.socket(target, Type::STREAM, Protocol::TCP)
+            .map(Into::into)
+    }
+
+    /// A UDP socket bound for `target`, not connected yet and non-blocking.
+    pub fn udp_socket(&self, target: SocketAddr) -> io::Result<std::net::UdpSocket> {
+        self.socket(target, Type::DGRAM, Protocol::UDP)
+            .map(Into::into)
+    }
+
+    /// Opens a TCP connection to `target` from this source.
+    pub async fn connect(&self, target: SocketAddr) -> io::Result<TcpStream> {
+        if self.is_default() {
+            return TcpStream::connect(target).await;
+        }
+        let socket = self.socket(target, Type::STREAM, Protocol::TCP)?;
+        let attempt = socket.connect(&target.into());
+        let stream = Async::new(std::net::TcpStream::from(socket))?;
+        match attempt {
+            Ok(()) => {}
+            Err(e) if in_progress(&e) => {
+                stream.writable().await?;
+                if let Some(e) = stream.get_ref().take_error()? {
+                    return Err(e);
+                }
+            }
+            Err(e) => return Err(e),
+        }
+        Ok(TcpStream::from(stream.into_inner()?))
+    }
+
+    /// A UDP socket to probe `target` from.
+    pub async fn bind_udp(&self, target: SocketAddr) -> io::Result<UdpSocket> {
+        if self.is_default() {
+            return UdpSocket::bind(self.local_addr(target)).await;
+        }
+        Ok(UdpSocket::from(self.udp_socket(target)?))
+    }
+
+    /// The interface given to `with_interface`.
+    pub fn interface(&self) -> Option<&str> {
+        self.interface.as_deref()
+    }
+
+    /// Whether the address probes leave from is chosen here rather than
+    /// by the routing table.
+    pub(crate) fn pins_address(&self) -> bool {
+        self.ip.is_some() || self.interface.is_some()
+    }
+
+    /// Whether probes leave from the ports of `with_ports`, which sockets
+    /// to the same target may end up sharing.
+    pub(crate) fn pins_ports(&self) -> bool {
+        self.ports.is_some()
+    }
+
+    pub(crate) fn first_port(&self) -> Option<u16> {
+        self.ports.as_ref().map(|ports| *ports.start())
+    }
+
+    fn is_default(&self) -> bool {
+        self.ip.is_none() && self.interface.is_none() && self.ports.is_none()
+    }
+
+    fn socket(&self, target: SocketAddr, kind: Type, protocol: Protocol) -> io::Result<Socket> {
+        let socket = Socket::new(Domain::for_address(target), kind, Some(protocol))?;
+        socket.set_nonblocking(true)?;
+        if self.ports.is_some() {
+            // Probes in flight share the source ports, and so do probes
+            // that just finished with those still in TIME_WAIT.
+            socket.set_reuse_address(true)?;
+        }
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if let Some(interface) = &self.interface {
+            // Needs CAP_NET_RAW on older kernels, the address alone still
+            // does most of the job without it.
+            if let Err(e) = socket.bind_device(Some(interface.as_bytes())) {
+                log::debug!("Could not bind to interface {}: {}", interface, e);
+            }
+        }
+        socket.bind(&self.local_addr(target).into())?;
+        Ok(socket)
+    }
+}
+
+/// Whether a non-blocking connect is under way rather than failed.
+fn in_progress(error: &io::Error) -> bool {
+    #[cfg(unix)]
+    if error.raw_os_error() == Some(libc::EINPROGRESS) {
+        return true;
+    }
+    error.kind() == io::ErrorKind::WouldBlock
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Source;
+    use async_std::task::block_on;
+    use std::net::{IpAddr, SocketAddr, TcpListener};
+
+    const LOCALHOST: IpAddr = IpAddr::V4(std::net::Ipv4Addr::LOCALHOST);
+
+    #[test]
+    fn picks_the_address_of_the_target_family() {
+        let source = Source::default().with_ip(LOCALHOST).with_ports(5300..=5301);
+        let v4: SocketAddr = "10.0.0.1:80".parse().unwrap();
+        let v6: SocketAddr = "[2001:db8::1]:80".parse().unwrap();
+
+        assert_eq!(source.local_addr(v4), SocketAddr::new(LOCALHOST, 5300));
+        assert_eq!(source.local_addr(v6), "[::]:5301".parse().unwrap());
+        assert_eq!(
+            Source::default().local_addr(v4),
+            "0.0.0.0:0".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn connects_from_the_source_port() {
+        let listener = TcpListener::bind((LOCALHOST, 0)).unwrap();
+        let target = listener.local_addr().unwrap();
+        let free_port = TcpListener::bind((LOCALHOST, 0))
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let source = Source::default()
+            .with_ip(LOCALHOST)
+            .with_ports(free_port..=free_port);
+
+        let stream = block_on(source.connect(target)).unwrap();
+        let (_, peer) = listener.accept().unwrap();
+        assert_eq!(peer, SocketAddr::new(LOCALHOST, free_port));
+        drop(stream);
+
+        let udp = block_on(source.bind_udp(target)).unwrap();
+        assert_eq!(udp.local_addr().unwrap(), peer);
+    }
+
+    #[test]
+    fn refused_connections_are_errors() {
+        let closed = TcpListener::bind((LOCALHOST, 0))
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let source = Source::default().with_ip(LOCALHOST);
+        let error = block_on(source.connect(closed)).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::ConnectionRefused);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn interfaces_lend_their_address() {
+        let source = Source::default().with_interface("lo").unwrap();
+        let target: SocketAddr = "127.0.0.1:80".parse().unwrap();
+        assert_eq!(source.local_addr(target).ip(), LOCALHOST);
+        assert!(Source::default().with_interface("no-such-if0").is_err());
+    }
+}
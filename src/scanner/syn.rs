@@ -0,0 +1,421 @@
+//! Half-open (SYN) scanning over raw sockets.
+//!
+//! A SYN goes out and the reply tells the state: SYN/ACK is open, RST is
+//! closed and an ICMP unreachable is filtered. The handshake is never
+//! completed, the kernel answers the SYN/ACK of a connection it knows
+//! nothing about with a RST of its own. Nothing is kept in conntrack and no
+//! file descriptor is used per socket.
+//!
+//! Opening the raw sockets needs root or `CAP_NET_RAW`. Only IPv4 targets
+//! are supported.
+use super::Source;
+use futures::channel::oneshot;
+use log::debug;
+use pnet_packet::icmp::destination_unreachable::DestinationUnreachablePacket;
+use pnet_packet::icmp::{IcmpPacket, IcmpTypes};
+use pnet_packet::ip::IpNextHeaderProtocols;
+use pnet_packet::ipv4::Ipv4Packet;
+use pnet_packet::tcp::{self, MutableTcpPacket, TcpFlags, TcpOption, TcpPacket};
+use pnet_packet::Packet;
+use pnet_transport::TransportChannelType::Layer4;
+use pnet_transport::TransportProtocol::Ipv4;
+use pnet_transport::{
+    icmp_packet_iter, tcp_packet_iter, transport_channel, TransportReceiver, TransportSender,
+};
+use std::collections::HashMap;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, SocketAddrV4, UdpSocket};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::Duration;
+
+/// Size of the kernel buffers behind the raw sockets.
+const CHANNEL_BUFFER_SIZE: usize = 1 << 16;
+/// How often the receive threads check whether the scanner is gone.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Length of a SYN carrying a single MSS option.
+const SYN_LENGTH: usize = 24;
+
+/// What came back for a SYN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Reply {
+    SynAck,
+    Reset,
+    Unreachable,
+}
+
+/// Sends SYNs and matches the replies to them.
+///
+/// Clones share the raw sockets. The receive threads stop shortly after
+/// the last clone is dropped.
+#[derive(Clone)]
+pub struct SynScanner {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    sender: Mutex<TransportSender>,
+    waiting: Mutex<HashMap<SocketAddrV4, oneshot::Sender<Reply>>>,
+    sources: Mutex<HashMap<Ipv4Addr, Ipv4Addr>>,
+    source_port: u16,
+    sequence: u32,
+}
+
+impl std::fmt::Debug for SynScanner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SynScanner")
+            .field("source_port", &self.shared.source_port)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SynScanner {
+    /// Opens the raw sockets and starts listening for replies.
+    ///
+    /// Fails with `PermissionDenied` when the process is not allowed to
+    /// open raw sockets.
+    pub fn new() -> io::Result<Self> {
+        // Stay clear of the ports the kernel hands out for connect(), a
+        // reply to us must never be taken for one of its own connections.
+        Self::open(rand::random::<u16>() % 16384 + 10000)
+    }
+
+    /// Like `new`, sending from the first port of `source` if it has any.
+    ///
+    /// The kernel fills in the source address of raw packets from the
+    /// routing table, so a source that pins the address or interface is
+    /// refused with `Unsupported`.
+    pub fn from_source(source: &Source) -> io::Result<Self> {
+        if source.pins_address() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "SYN scan cannot pick its source address",
+            ));
+        }
+        match source.first_port() {
+            Some(port) => Self::open(port),
+            None => Self::new(),
+        }
+    }
+
+    fn open(source_port: u16) -> io::Result<Self> {
+        let (sender, tcp_receiver) = transport_channel(
+            CHANNEL_BUFFER_SIZE,
+            Layer4(Ipv4(IpNextHeaderProtocols::Tcp)),
+        )?;
+        let (_, icmp_receiver) = transport_channel(
+            CHANNEL_BUFFER_SIZE,
+            Layer4(Ipv4(IpNextHeaderProtocols::Icmp)),
+        )?;
+
+        let shared = Arc::new(Shared {
+            sender: Mutex::new(sender),
+            waiting: Mutex::new(HashMap::new()),
+            sources: Mutex::new(HashMap::new()),
+            source_port,
+            sequence: rand::random(),
+        });
+
+        let weak = Arc::downgrade(&shared);
+        thread::Builder::new()
+            .name("syn-tcp".into())
+            .spawn(move || receive_tcp(tcp_receiver, &weak))?;
+        let weak = Arc::downgrade(&shared);
+        thread::Builder::new()
+            .name("syn-icmp".into())
+            .spawn(move || receive_icmp(icmp_receiver, &weak))?;
+
+        Ok(Self { shared })
+    }
+
+    /// Sends one SYN to `socket` and waits up to `timeout` for the reply.
+    /// `None` means nothing came back.
+    pub(crate) async fn probe(
+        &self,
+        socket: SocketAddrV4,
+        timeout: Duration,
+    ) -> io::Result<Option<Reply>> {
+        self.send_and_wait(socket, TcpFlags::SYN, timeout).await
+    }
+
+    /// Sends a lone ACK to `socket`. Any live host answers it with a RST,
+    /// whether the port is open or not, and stateless firewalls that drop
+    /// SYNs often let it through.
+    pub(crate) async fn probe_ack(
+        &self,
+        socket: SocketAddrV4,
+        timeout: Duration,
+    ) -> io::Result<Option<Reply>> {
+        self.send_and_wait(socket, TcpFlags::ACK, timeout).await
+    }
+
+    async fn send_and_wait(
+        &self,
+        socket: SocketAddrV4,
+        flags: u8,
+        timeout: Duration,
+    ) -> io::Result<Option<Reply>> {
+        let source = self.source_for(*socket.ip())?;
+        let (tx, rx) = oneshot::channel();
+        self.shared.waiting.lock().unwrap().insert(socket, tx);
+
+        let packet = probe_packet(
+            SocketAddrV4::new(source, self.shared.source_port),
+            socket,
+            flags,
+            self.shared.sequence,
+        );
+        let sent = self
+            .shared
+            .sender
+            .lock()
+            .unwrap()
+            .send_to(TcpPacket::new(&packet).unwrap(), IpAddr::V4(*socket.ip()));
+        if let Err(e) = sent {
+            self.shared.waiting.lock().unwrap().remove(&socket);
+            return Err(e);
+        }
+
+        let reply = async_std::io::timeout(timeout, async {
+            rx.await
+                .map_err(|_| io::Error::other("SYN receiver stopped"))
+        })
+        .await;
+        match reply {
+            Ok(reply) => Ok(Some(reply)),
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => {
+                self.shared.waiting.lock().unwrap().remove(&socket);
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The address the kernel would send from to reach `destination`. It
+    /// goes into the pseudo header of the checksum.
+    fn source_for(&self, destination: Ipv4Addr) -> io::Result<Ipv4Addr> {
+        if let Some(source) = self.shared.sources.lock().unwrap().get(&destination) {
+            return Ok(*source);
+        }
+        // Connecting a UDP socket sends nothing but makes the kernel pick
+        // the route.
+        let probe = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+        probe.connect((destination, 9))?;
+        let IpAddr::V4(source) = probe.local_addr()?.ip() else {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "SYN scan only supports IPv4",
+            ));
+        };
+        self.shared
+            .sources
+            .lock()
+            .unwrap()
+            .insert(destination, source);
+        Ok(source)
+    }
+}
+
+impl Shared {
+    fn answer(&self, socket: SocketAddrV4, reply: Reply) {
+        if let Some(waiter) = self.waiting.lock().unwrap().remove(&socket) {
+            // The probe may have timed out in the meantime.
+            let _ = waiter.send(reply);
+        }
+    }
+}
+
+fn receive_tcp(mut receiver: TransportReceiver, shared: &Weak<Shared>) {
+    let mut packets = tcp_packet_iter(&mut receiver);
+    while let Some(shared) = shared.upgrade() {
+        match packets.next_with_timeout(POLL_INTERVAL) {
+            Ok(Some((packet, IpAddr::V4(from)))) => {
+                if let Some(reply) = parse_tcp_reply(&packet, shared.source_port, shared.sequence) {
+                    shared.answer(SocketAddrV4::new(from, packet.get_source()), reply);
+                }
+            }
+            Ok(_) => {}
+            Err(e) => debug!("SYN scan: receiving TCP failed: {}", e),
+        }
+    }
+}
+
+fn receive_icmp(mut receiver: TransportReceiver, shared: &Weak<Shared>) {
+    let mut packets = icmp_packet_iter(&mut receiver);
+    while let Some(shared) = shared.upgrade() {
+        match packets.next_with_timeout(POLL_INTERVAL) {
+            Ok(Some((packet, _))) => {
+                if let Some(socket) = parse_icmp_reply(&packet, shared.source_port) {
+                    shared.answer(socket, Reply::Unreachable);
+                }
+            }
+            Ok(_) => {}
+            Err(e) => debug!("SYN scan: receiving ICMP failed: {}", e),
+        }
+    }
+}
+
+/// Builds a probe with a valid checksum and an MSS option, like the SYNs
+/// the kernel sends. Some stacks ignore SYNs without options.
+///
+/// An ACK probe acknowledges `sequence`, so the RST it gets back carries
+/// `sequence` as its own sequence number.
+fn probe_packet(
+    source: SocketAddrV4,
+    destination: SocketAddrV4,
+    flags: u8,
+    sequence: u32,
+) -> Vec<u8> {
+    let mut buffer = vec![0; SYN_LENGTH];
+    let mut probe = MutableTcpPacket::new(&mut buffer).unwrap();
+    probe.set_source(source.port());
+    probe.set_destination(destination.port());
+    probe.set_sequence(sequence);
+    if flags & TcpFlags::ACK != 0 {
+        probe.set_acknowledgement(sequence);
+    }
+    probe.set_data_offset((SYN_LENGTH / 4) as u8);
+    probe.set_flags(flags);
+    probe.set_window(1024);
+    probe.set_options(&[TcpOption::mss(1460)]);
+    let checksum = tcp::ipv4_checksum(&probe.to_immutable(), source.ip(), destination.ip());
+    probe.set_checksum(checksum);
+    buffer
+}
+
+/// Tells whether `packet` answers one of our probes. The raw socket sees
+/// all TCP traffic of the host, so the port and sequence numbers must
+/// match: a SYN is acknowledged, an ACK is reset with the number it
+/// acknowledged.
+fn parse_tcp_reply(packet: &TcpPacket, source_port: u16, sequence: u32) -> Option<Reply> {
+    if packet.get_destination() != source_port {
+        return None;
+    }
+    let flags = packet.get_flags();
+    let answers_syn = packet.get_acknowledgement() == sequence.wrapping_add(1);
+    if flags & TcpFlags::RST != 0 && (answers_syn || packet.get_sequence() == sequence) {
+        Some(Reply::Reset)
+    } else if answers_syn
+        && flags & (TcpFlags::SYN | TcpFlags::ACK) == TcpFlags::SYN | TcpFlags::ACK
+    {
+        Some(Reply::SynAck)
+    } else {
+        None
+    }
+}
+
+/// Returns the socket one of our SYNs was sent to when `packet` says it
+/// was unreachable. Codes follow nmap: host, protocol and port unreachable
+/// and the administratively prohibited ones.
+fn parse_icmp_reply(packet: &IcmpPacket, source_port: u16) -> Option<SocketAddrV4> {
+    if packet.get_icmp_type() != IcmpTypes::DestinationUnreachable
+        || ![1, 2, 3, 9, 10, 13].contains(&packet.get_icmp_code().0)
+    {
+        return None;
+    }
+    let unreachable = DestinationUnreachablePacket::new(packet.packet())?;
+    let original = Ipv4Packet::new(unreachable.payload())?;
+    if original.get_next_level_protocol() != IpNextHeaderProtocols::Tcp {
+        return None;
+    }
+    // Only the first 8 bytes of the TCP header are guaranteed to be quoted.
+    let header = original.payload();
+    if header.len() < 4 || u16::from_be_bytes([header[0], header[1]]) != source_port {
+        return None;
+    }
+    let port = u16::from_be_bytes([header[2], header[3]]);
+    Some(SocketAddrV4::new(original.get_destination(), port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pnet_packet::icmp::{IcmpCode, MutableIcmpPacket};
+    use pnet_packet::ipv4::MutableIpv4Packet;
+
+    const SOURCE: SocketAddrV4 = SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 1), 40000);
+    const TARGET: SocketAddrV4 = SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 2), 443);
+
+    fn reply(flags: u8, ack: u32) -> Vec<u8> {
+        let mut buffer = vec![0; 20];
+        let mut packet = MutableTcpPacket::new(&mut buffer).unwrap();
+        packet.set_source(TARGET.port());
+        packet.set_destination(SOURCE.port());
+        packet.set_acknowledgement(ack);
+        packet.set_data_offset(5);
+        packet.set_flags(flags);
+        buffer
+    }
+
+    fn unreachable(code: u8) -> Vec<u8> {
+        let mut original = vec![0; 28];
+        let mut ip = MutableIpv4Packet::new(&mut original).unwrap();
+        ip.set_version(4);
+        ip.set_header_length(5);
+        // Routers may quote only the first 8 bytes of our 24-byte SYN.
+        ip.set_total_length(20 + SYN_LENGTH as u16);
+        ip.set_next_level_protocol(IpNextHeaderProtocols::Tcp);
+        ip.set_source(*SOURCE.ip());
+        ip.set_destination(*TARGET.ip());
+        original[20..22].copy_from_slice(&SOURCE.port().to_be_bytes());
+        original[22..24].copy_from_slice(&TARGET.port().to_be_bytes());
+
+        let mut buffer = vec![0; 8];
+        buffer.extend(original);
+        let mut icmp = MutableIcmpPacket::new(&mut buffer).unwrap();
+        icmp.set_icmp_type(IcmpTypes::DestinationUnreachable);
+        icmp.set_icmp_code(IcmpCode(code));
+        buffer
+    }
+
+    #[test]
+    fn syn_packet_is_well_formed() {
+        let buffer = probe_packet(SOURCE, TARGET, TcpFlags::SYN, 1234);
+        let syn = TcpPacket::new(&buffer).unwrap();
+        assert_eq!(syn.get_source(), 40000);
+        assert_eq!(syn.get_destination(), 443);
+        assert_eq!(syn.get_sequence(), 1234);
+        assert_eq!(syn.get_flags(), TcpFlags::SYN);
+        assert_eq!(usize::from(syn.get_data_offset()) * 4, buffer.len());
+        assert_eq!(
+            syn.get_checksum(),
+            tcp::ipv4_checksum(&syn, SOURCE.ip(), TARGET.ip())
+        );
+    }
+
+    #[test]
+    fn tcp_replies_are_matched_to_our_syns() {
+        let syn_ack = reply(TcpFlags::SYN | TcpFlags::ACK, 1235);
+        let reset = reply(TcpFlags::RST | TcpFlags::ACK, 1235);
+        let stray = reply(TcpFlags::SYN | TcpFlags::ACK, 99);
+        let parse = |buffer: &[u8]| parse_tcp_reply(&TcpPacket::new(buffer).unwrap(), 40000, 1234);
+
+        assert_eq!(parse(&syn_ack), Some(Reply::SynAck));
+        assert_eq!(parse(&reset), Some(Reply::Reset));
+        assert_eq!(parse(&stray), None);
+        assert_eq!(
+            parse_tcp_reply(&TcpPacket::new(&syn_ack).unwrap(), 40001, 1234),
+            None
+        );
+
+        // The RST answering an ACK probe echoes what the probe acknowledged.
+        let mut ack_reset = reply(TcpFlags::RST, 0);
+        MutableTcpPacket::new(&mut ack_reset)
+            .unwrap()
+            .set_sequence(1234);
+        assert_eq!(parse(&ack_reset), Some(Reply::Reset));
+    }
+
+    #[test]
+    fn icmp_unreachable_points_back_at_the_probed_socket() {
+        let prohibited = unreachable(13);
+        let packet = IcmpPacket::new(&prohibited).unwrap();
+        assert_eq!(parse_icmp_reply(&packet, 40000), Some(TARGET));
+        assert_eq!(parse_icmp_reply(&packet, 40001), None);
+
+        // Fragmentation needed says nothing about the port.
+        let fragmentation = unreachable(4);
+        let packet = IcmpPacket::new(&fragmentation).unwrap();
+        assert_eq!(parse_icmp_reply(&packet, 40000), None);
+    }
+}
@@ -0,0 +1,218 @@
+//! What carries the probes of a `Scanner` to their targets.
+//!
+//! The scanner decides what to probe, when, and what the answers mean. A
+//! transport only opens connections and moves datagrams, which is the part
+//! that differs between the real network, a proxy and a test.
+use super::{PortState, Source};
+use async_std::io as async_io;
+use async_std::net::{TcpStream, UdpSocket};
+use futures::future::BoxFuture;
+use futures::{AsyncRead, AsyncWrite, FutureExt};
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Opens TCP connections and exchanges UDP datagrams on behalf of a
+/// `Scanner`.
+///
+/// Timeouts are the scanner's business. A probe that gets no answer never
+/// completes and is dropped once its time is up, so transports do not
+/// need timers of their own, save for the legs of a connect that do not
+/// involve the target, see `connect_within`.
+pub trait Transport: fmt::Debug + Send + Sync {
+    type TcpStream: AsyncRead + AsyncWrite + Unpin + Send;
+    type UdpSocket: Send + Sync;
+
+    /// Connects to `target`. A refusal is reported as
+    /// `io::ErrorKind::ConnectionRefused`, other errors say nothing about
+    /// the port.
+    fn connect(&self, target: SocketAddr) -> BoxFuture<'_, io::Result<Self::TcpStream>>;
+
+    /// Connects to `target`, giving up with `io::ErrorKind::TimedOut` when
+    /// the target does not answer within `timeout`. A transport that gets
+    /// somewhere else first, like a proxy, times that part on its own so a
+    /// slow hop does not pass for a filtered port.
+    fn connect_within(
+        &self,
+        target: SocketAddr,
+        timeout: Duration,
+    ) -> BoxFuture<'_, io::Result<Self::TcpStream>> {
+        async_io::timeout(timeout, self.connect(target)).boxed()
+    }
+
+    /// A UDP socket that only talks to `target`.
+    fn udp_socket(&self, target: SocketAddr) -> BoxFuture<'_, io::Result<Self::UdpSocket>>;
+
+    /// Sends `payload` to the target of `socket`.
+    fn send<'a>(
+        &'a self,
+        socket: &'a Self::UdpSocket,
+        payload: &'a [u8],
+    ) -> BoxFuture<'a, io::Result<()>>;
+
+    /// Waits for whatever the target of `socket` sends back, be it an
+    /// answer or an ICMP error.
+    fn recv<'a>(
+        &'a self,
+        socket: &'a Self::UdpSocket,
+        buf: &'a mut [u8],
+    ) -> BoxFuture<'a, io::Result<UdpReply>>;
+
+    /// Whether every `udp_socket` for a target gets a local address of its
+    /// own. When it does not, the scanner sends all payloads for a target
+    /// through a single socket.
+    fn distinct_udp_sockets(&self) -> bool {
+        true
+    }
+
+    /// How many probes may be in flight at once through this transport,
+    /// on top of the scanner's own limits.
+    fn max_in_flight(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// What came back to a UDP probe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UdpReply {
+    /// An answer of this many bytes.
+    Answer(usize),
+    Icmp(IcmpEvent),
+}
+
+/// ICMP errors that tell something about a probed UDP port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IcmpEvent {
+    PortUnreachable,
+    HostUnreachable,
+    NetworkUnreachable,
+    /// Administratively prohibited, a firewall said no.
+    Prohibited,
+}
+
+impl IcmpEvent {
+    pub fn port_state(self) -> PortState {
+        match self {
+            IcmpEvent::PortUnreachable => PortState::Closed,
+            IcmpEvent::HostUnreachable | IcmpEvent::NetworkUnreachable => PortState::Unreachable,
+            IcmpEvent::Prohibited => PortState::Filtered,
+        }
+    }
+
+    /// The ICMP error the OS reports as `error` on a connected socket.
+    fn from_error(error: &io::Error) -> Option<Self> {
+        // ICMP port unreachable is ECONNREFUSED, or WSAECONNRESET on
+        // Windows.
+        match error.kind() {
+            io::ErrorKind::ConnectionRefused | io::ErrorKind::ConnectionReset => {
+                Some(IcmpEvent::PortUnreachable)
+            }
+            io::ErrorKind::HostUnreachable => Some(IcmpEvent::HostUnreachable),
+            io::ErrorKind::NetworkUnreachable => Some(IcmpEvent::NetworkUnreachable),
+            io::ErrorKind::PermissionDenied => Some(IcmpEvent::Prohibited),
+            _ => None,
+        }
+    }
+}
+
+/// The network of the OS, probed from `Source`.
+#[derive(Debug, Clone, Default)]
+pub struct OsTransport {
+    source: Source,
+}
+
+impl OsTransport {
+    pub fn new(source: Source) -> Self {
+        Self { source }
+    }
+}
+
+/// A connected UDP socket of the OS.
+#[derive(Debug)]
+pub struct OsUdpSocket {
+    socket: UdpSocket,
+    /// The OS hands out an ICMP error to whichever call comes next, which
+    /// may be a send. It is kept here for the next `recv`.
+    icmp: Mutex<Option<IcmpEvent>>,
+}
+
+impl Transport for OsTransport {
+    type TcpStream = TcpStream;
+    type UdpSocket = OsUdpSocket;
+
+    fn connect(&self, target: SocketAddr) -> BoxFuture<'_, io::Result<TcpStream>> {
+        self.source.connect(target).boxed()
+    }
+
+    fn udp_socket(&self, target: SocketAddr) -> BoxFuture<'_, io::Result<OsUdpSocket>> {
+        async move {
+            let socket = self.source.bind_udp(target).await?;
+            socket.connect(target).await?;
+            Ok(OsUdpSocket {
+                socket,
+                icmp: Mutex::new(None),
+            })
+        }
+        .boxed()
+    }
+
+    fn send<'a>(
+        &'a self,
+        socket: &'a OsUdpSocket,
+        payload: &'a [u8],
+    ) -> BoxFuture<'a, io::Result<()>> {
+        async move {
+            match socket.socket.send(payload).await {
+                Ok(_) => Ok(()),
+                Err(e) => match IcmpEvent::from_error(&e) {
+                    Some(event) => {
+                        *socket.icmp.lock().unwrap() = Some(event);
+                        Ok(())
+                    }
+                    None => Err(e),
+                },
+            }
+        }
+        .boxed()
+    }
+
+    fn recv<'a>(
+        &'a self,
+        socket: &'a OsUdpSocket,
+        buf: &'a mut [u8],
+    ) -> BoxFuture<'a, io::Result<UdpReply>> {
+        async move {
+            if let Some(event) = socket.icmp.lock().unwrap().take() {
+                return Ok(UdpReply::Icmp(event));
+            }
+            match socket.socket.recv(buf).await {
+                Ok(size) => Ok(UdpReply::Answer(size)),
+                Err(e) => IcmpEvent::from_error(&e).map(UdpReply::Icmp).ok_or(e),
+            }
+        }
+        .boxed()
+    }
+
+    fn distinct_udp_sockets(&self) -> bool {
+        !self.source.pins_ports()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IcmpEvent;
+    use std::io;
+
+    #[test]
+    fn only_errors_from_the_network_are_icmp() {
+        let refused = io::Error::from(io::ErrorKind::ConnectionRefused);
+        assert_eq!(
+            IcmpEvent::from_error(&refused),
+            Some(IcmpEvent::PortUnreachable)
+        );
+        let exhausted = io::Error::from(io::ErrorKind::AddrNotAvailable);
+        assert_eq!(IcmpEvent::from_error(&exhausted), None);
+    }
+}
@@ -0,0 +1,239 @@
+//! Sends service probes to open ports and matches what comes back.
+use super::{Probe, ProbeProtocol, ServiceMatch, ServiceProbes};
+use crate::scanner::{read_banner, Banner, BannerGrab, Source};
+use async_std::io;
+use futures::io::AsyncWriteExt;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The intensity nmap uses unless told otherwise.
+pub const DEFAULT_INTENSITY: u8 = 7;
+const MAX_INTENSITY: u8 = 9;
+/// Answers are cut off after this many bytes.
+const MAX_RESPONSE: usize = 4096;
+
+/// Identifies the services behind open ports.
+#[derive(Debug, Clone)]
+pub struct ServiceDetector {
+    probes: Arc<ServiceProbes>,
+    intensity: u8,
+    timeout: Duration,
+    source: Source,
+}
+
+impl ServiceDetector {
+    pub fn new(probes: Arc<ServiceProbes>) -> Self {
+        Self {
+            probes,
+            intensity: DEFAULT_INTENSITY,
+            timeout: Duration::from_secs(2),
+            source: Source::default(),
+        }
+    }
+
+    /// How rare a probe may be and still be sent, from 0 to 9. Probes that
+    /// list the port are sent whatever the intensity.
+    pub fn with_intensity(mut self, intensity: u8) -> Self {
+        self.intensity = intensity.min(MAX_INTENSITY);
+        self
+    }
+
+    /// Bounds every connect, and how long to wait for each answer on top of
+    /// the probes' own `totalwaitms`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sends the probes from `source`, like the scan that found the port.
+    pub fn with_source(mut self, source: Source) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Identifies the service behind `socket`. A `banner` the scanner
+    /// already read spares the NULL probe its connect.
+    pub async fn detect(
+        &self,
+        socket: SocketAddr,
+        protocol: ProbeProtocol,
+        banner: Option<&Banner>,
+    ) -> Option<ServiceMatch> {
+        if self.probes.is_excluded(socket.port(), protocol) {
+            return None;
+        }
+
+        let mut soft: Option<ServiceMatch> = None;
+        for probe in self
+            .probes
+            .probes_for(socket.port(), protocol, self.intensity)
+        {
+            // After a softmatch, only probes that may tell the version of
+            // that service are worth sending.
+            if soft
+                .as_ref()
+                .is_some_and(|found| !probe.can_identify(&found.service))
+            {
+                continue;
+            }
+
+            let response = match banner {
+                Some(banner) if probe.is_null() && !banner.nudged => Ok(Some(banner.data.clone())),
+                _ => self.exchange(socket, probe).await,
+            };
+            let response = match response {
+                Ok(Some(response)) => response,
+                Ok(None) => continue,
+                // The port stopped taking connections, no other probe will get through.
+                Err(_) => break,
+            };
+
+            match self.probes.match_response(probe, &response) {
+                Some(found) if !found.soft => return Some(found),
+                Some(found) => {
+                    soft.get_or_insert(found);
+                }
+                None => {}
+            }
+        }
+        soft
+    }
+
+    /// Sends `probe` and returns the answer, `None` when there was none.
+    async fn exchange(&self, socket: SocketAddr, probe: &Probe) -> io::Result<Option<Vec<u8>>> {
+        let options = BannerGrab {
+            timeout: probe.total_wait.min(self.timeout),
+            max_bytes: MAX_RESPONSE,
+        };
+
+        match probe.protocol {
+            ProbeProtocol::Tcp => {
+                let mut stream = io::timeout(self.timeout, self.source.connect(socket)).await?;
+                if !probe.payload.is_empty() && stream.write_all(&probe.payload).await.is_err() {
+                    return Ok(None);
+                }
+                Ok(read_banner(&mut stream, &options).await)
+            }
+            ProbeProtocol::Udp => {
+                let udp_socket = self.source.bind_udp(socket).await?;
+                udp_socket.connect(socket).await?;
+                udp_socket.send(&probe.payload).await?;
+
+                let mut buf = vec![0; MAX_RESPONSE];
+                match io::timeout(options.timeout, udp_socket.recv(&mut buf)).await {
+                    Ok(size) => {
+                        buf.truncate(size);
+                        Ok(Some(buf))
+                    }
+                    Err(e) if e.kind() == io::ErrorKind::TimedOut => Ok(None),
+                    Err(e) => Err(e),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ServiceDetector;
+    use crate::scanner::{Banner, Source};
+    use crate::service::{ProbeProtocol, ServiceProbes};
+    use async_std::task::block_on;
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpListener};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    fn detector() -> ServiceDetector {
+        ServiceDetector::new(ServiceProbes::embedded()).with_timeout(Duration::from_millis(300))
+    }
+
+    /// Serves every connection with `answer`, which gets what the client sent.
+    fn serve(answer: fn(&[u8]) -> Vec<u8>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                stream
+                    .set_read_timeout(Some(Duration::from_millis(100)))
+                    .unwrap();
+                let mut request = [0; 256];
+                let len = stream.read(&mut request).unwrap_or(0);
+                let _ = stream.write_all(&answer(&request[..len]));
+            }
+        });
+        addr
+    }
+
+    #[test]
+    fn identifies_http_from_the_get_request() {
+        let addr = serve(|request| {
+            if request.starts_with(b"GET") {
+                b"HTTP/1.1 200 OK\r\nServer: nginx/1.24.0\r\n\r\n".to_vec()
+            } else {
+                vec![]
+            }
+        });
+
+        let found = block_on(detector().detect(addr, ProbeProtocol::Tcp, None)).unwrap();
+        assert_eq!(found.to_string(), "http nginx 1.24.0");
+    }
+
+    #[test]
+    fn uses_the_banner_for_the_null_probe() {
+        let closed = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap()
+        };
+        let banner = Banner {
+            data: b"SSH-2.0-dropbear_2022.83\r\n".to_vec(),
+            nudged: false,
+        };
+
+        let found = block_on(detector().detect(closed, ProbeProtocol::Tcp, Some(&banner)));
+        assert_eq!(
+            found.unwrap().to_string(),
+            "ssh Dropbear sshd 2022.83 (protocol 2.0)"
+        );
+    }
+
+    #[test]
+    fn probes_leave_from_the_source() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let peer = thread::spawn(move || {
+            let (mut stream, peer) = listener.accept().unwrap();
+            let _ = stream.write_all(b"SSH-2.0-OpenSSH_9.6\r\n");
+            peer
+        });
+        let source_port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let detector = detector().with_source(
+            Source::default()
+                .with_ip(addr.ip())
+                .with_ports(source_port..=source_port),
+        );
+        let found = block_on(detector.detect(addr, ProbeProtocol::Tcp, None)).unwrap();
+        assert_eq!(found.to_string().split(' ').next(), Some("ssh"));
+        assert_eq!(
+            peer.join().unwrap(),
+            SocketAddr::new(addr.ip(), source_port)
+        );
+    }
+
+    #[test]
+    fn excluded_ports_are_left_alone() {
+        let probes = Arc::new(ServiceProbes::parse("Exclude T:1-65535").unwrap());
+        let addr = serve(|_| b"SSH-2.0-OpenSSH_9.6\r\n".to_vec());
+
+        let found = block_on(ServiceDetector::new(probes).detect(addr, ProbeProtocol::Tcp, None));
+        assert_eq!(found, None);
+    }
+}
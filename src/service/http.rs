@@ -0,0 +1,585 @@
+//! HTTP fingerprinting of web interfaces.
+//!
+//! Fetches `/` the way a browser would, follows redirects that stay on the
+//! host, and keeps what tells web interfaces apart: status, `Server` and
+//! `X-Powered-By` headers, the page title, the auth realm and a favicon hash.
+use super::tls::client_config;
+use crate::scanner::HTTP_PORTS;
+use async_std::io;
+use async_std::net::TcpStream;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use futures::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use futures_rustls::TlsConnector;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rustls::pki_types::ServerName;
+use rustls::ClientConfig;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Ports where web interfaces usually sit behind TLS.
+pub const HTTPS_PORTS: &[u16] = &[443, 4443, 5001, 7443, 8443, 9443];
+const MAX_REDIRECTS: usize = 5;
+/// Responses are cut off after this many bytes, headers included.
+const MAX_RESPONSE: usize = 256 * 1024;
+const USER_AGENT: &str = concat!("SCANNR/", env!("CARGO_PKG_VERSION"));
+/// Titles longer than this are cut, some pages put whole scripts there.
+const MAX_TITLE: usize = 200;
+
+static TITLE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<title[^>]*>(.*?)</title>").unwrap());
+static ICON_LINK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?is)<link\b[^>]*\brel\s*=\s*["']?[^"'>]*\bicon\b[^>]*>"#).unwrap());
+static HREF: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?is)\bhref\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s>]+))"#).unwrap());
+static REALM: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)\brealm\s*=\s*(?:"([^"]*)"|([^,\s]+))"#).unwrap());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Scheme {
+    Http,
+    Https,
+}
+
+impl Scheme {
+    fn default_port(self) -> u16 {
+        match self {
+            Scheme::Http => 80,
+            Scheme::Https => 443,
+        }
+    }
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Scheme::Http => "http",
+            Scheme::Https => "https",
+        })
+    }
+}
+
+/// How to talk to a web interface on `port`, `None` for ports that don't
+/// usually serve one.
+pub fn web_scheme(port: u16) -> Option<Scheme> {
+    if HTTPS_PORTS.contains(&port) {
+        Some(Scheme::Https)
+    } else if HTTP_PORTS.contains(&port) {
+        Some(Scheme::Http)
+    } else {
+        None
+    }
+}
+
+/// What the web interface behind a port looks like.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpFingerprint {
+    /// The URL that gave the final answer, after redirects.
+    pub url: String,
+    pub status: u16,
+    pub server: Option<String>,
+    pub powered_by: Option<String>,
+    pub title: Option<String>,
+    /// Every `Location` in order, the last one may point off the host and
+    /// was then not followed.
+    pub redirects: Vec<String>,
+    /// Realm of the `WWW-Authenticate` challenge.
+    pub auth_realm: Option<String>,
+    /// Shodan style favicon hash: murmur3 of the base64 encoded icon, so
+    /// values from `http.favicon.hash` searches can be compared directly.
+    pub favicon_hash: Option<i32>,
+}
+
+impl fmt::Display for HttpFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.status)?;
+        if let Some(title) = &self.title {
+            write!(f, " \"{title}\"")?;
+        }
+        if let Some(realm) = &self.auth_realm {
+            write!(f, " realm=\"{realm}\"")?;
+        }
+        if let Some(server) = &self.server {
+            write!(f, " {server}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Fetches `/` from web interfaces and fingerprints the answer.
+#[derive(Debug, Clone)]
+pub struct HttpProber {
+    tls: Arc<ClientConfig>,
+    timeout: Duration,
+}
+
+impl Default for HttpProber {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpProber {
+    pub fn new() -> Self {
+        Self {
+            tls: client_config(true),
+            timeout: Duration::from_secs(3),
+        }
+    }
+
+    /// Bounds the connect and handshake, then every read.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Fingerprints the web interface on `socket`. Fails only when the
+    /// first request gets no HTTP answer.
+    pub async fn fingerprint(
+        &self,
+        socket: SocketAddr,
+        scheme: Scheme,
+    ) -> io::Result<HttpFingerprint> {
+        let ip = socket.ip();
+        let mut target = Target {
+            scheme,
+            host: host_string(ip),
+            port: socket.port(),
+            path: "/".to_owned(),
+        };
+        let mut response = self.get(ip, &target).await?;
+        let mut redirects = Vec::new();
+
+        while (300..400).contains(&response.status) && redirects.len() < MAX_REDIRECTS {
+            let Some(next) = response
+                .header("location")
+                .and_then(|location| target.join(location))
+            else {
+                break;
+            };
+            redirects.push(next.to_string());
+            // Only the scanned host is fair game.
+            if next.host != target.host {
+                break;
+            }
+            match self.get(ip, &next).await {
+                Ok(next_response) => {
+                    target = next;
+                    response = next_response;
+                }
+                Err(_) => break,
+            }
+        }
+
+        let favicon_hash = self.favicon_hash(ip, &target, &response).await;
+        Ok(HttpFingerprint {
+            url: target.to_string(),
+            status: response.status,
+            server: response.header("server").map(str::to_owned),
+            powered_by: response.header("x-powered-by").map(str::to_owned),
+            title: page_title(&response.body),
+            redirects,
+            auth_realm: response.header("www-authenticate").and_then(auth_realm),
+            favicon_hash,
+        })
+    }
+
+    /// Hashes the icon the page links to, `/favicon.ico` otherwise.
+    async fn favicon_hash(&self, ip: IpAddr, page: &Target, response: &Response) -> Option<i32> {
+        let linked = String::from_utf8_lossy(&response.body);
+        let href = ICON_LINK
+            .find(&linked)
+            .and_then(|link| HREF.captures(link.as_str()))
+            .and_then(|href| href.iter().skip(1).flatten().next())
+            .map(|href| href.as_str().to_owned());
+        let icon = href
+            .and_then(|href| page.join(&href))
+            .filter(|icon| icon.host == page.host)
+            .or_else(|| page.join("/favicon.ico"))?;
+
+        let answer = self.get(ip, &icon).await.ok()?;
+        let is_html = answer
+            .header("content-type")
+            .is_some_and(|content_type| content_type.contains("html"));
+        if answer.status != 200 || answer.body.is_empty() || is_html {
+            return None;
+        }
+        Some(favicon_hash(&answer.body))
+    }
+
+    async fn get(&self, ip: IpAddr, target: &Target) -> io::Result<Response> {
+        let socket = SocketAddr::new(ip, target.port);
+        let request = format!(
+            "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {USER_AGENT}\r\nAccept: */*\r\nConnection: close\r\n\r\n",
+            target.path,
+            target.authority()
+        );
+
+        let stream = io::timeout(self.timeout, TcpStream::connect(socket)).await?;
+        match target.scheme {
+            Scheme::Http => self.exchange(stream, &request).await,
+            Scheme::Https => {
+                let name = match target.host.parse::<IpAddr>() {
+                    Ok(ip) => ServerName::from(ip),
+                    Err(_) => ServerName::try_from(target.host.clone())
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+                };
+                let connector = TlsConnector::from(Arc::clone(&self.tls));
+                let stream = io::timeout(self.timeout, connector.connect(name, stream)).await?;
+                self.exchange(stream, &request).await
+            }
+        }
+    }
+
+    async fn exchange<S>(&self, mut stream: S, request: &str) -> io::Result<Response>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        stream.write_all(request.as_bytes()).await?;
+        stream.flush().await?;
+
+        let mut raw = Vec::new();
+        let mut chunk = vec![0; 16 * 1024];
+        while raw.len() < MAX_RESPONSE {
+            match io::timeout(self.timeout, stream.read(&mut chunk)).await {
+                Ok(0) => break,
+                Ok(read) => raw.extend_from_slice(&chunk[..read]),
+                // A TLS peer hanging up without close_notify, or a
+                // keep-alive connection going quiet, still leaves the answer.
+                Err(_) if !raw.is_empty() => break,
+                Err(e) => return Err(e),
+            }
+            if Response::is_complete(&raw) {
+                break;
+            }
+        }
+
+        Response::parse(&raw)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "not an HTTP response"))
+    }
+}
+
+/// Where a request goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Target {
+    scheme: Scheme,
+    /// IP addresses are kept as written in URLs, IPv6 in brackets.
+    host: String,
+    port: u16,
+    path: String,
+}
+
+impl Target {
+    fn authority(&self) -> String {
+        if self.port == self.scheme.default_port() {
+            self.host.clone()
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+
+    /// Resolves `location` against this URL, as browsers do with redirects
+    /// and links.
+    fn join(&self, location: &str) -> Option<Target> {
+        let location = location.trim();
+        let (scheme, rest) = if let Some(rest) = location.strip_prefix("https://") {
+            (Scheme::Https, rest)
+        } else if let Some(rest) = location.strip_prefix("http://") {
+            (Scheme::Http, rest)
+        } else if let Some(rest) = location.strip_prefix("//") {
+            (self.scheme, rest)
+        } else if location.contains("://") {
+            return None;
+        } else {
+            let path = if location.starts_with('/') {
+                location.to_owned()
+            } else {
+                let base = &self.path[..=self.path.rfind('/').unwrap_or(0)];
+                format!("{base}{location}")
+            };
+            return Some(Target {
+                path,
+                ..self.clone()
+            });
+        };
+
+        let (authority, path) = match rest.find(['/', '?', '#']) {
+            Some(end) if rest[end..].starts_with('/') => (&rest[..end], rest[end..].to_owned()),
+            Some(end) => (&rest[..end], format!("/{}", &rest[end..])),
+            None => (rest, "/".to_owned()),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) if !port.contains(']') => (host, port.parse().ok()?),
+            _ => (authority, scheme.default_port()),
+        };
+        Some(Target {
+            scheme,
+            host: host.to_ascii_lowercase(),
+            port,
+            path: path.split('#').next().unwrap_or("/").to_owned(),
+        })
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://{}{}", self.scheme, self.authority(), self.path)
+    }
+}
+
+fn host_string(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(ip) => ip.to_string(),
+        IpAddr::V6(ip) => format!("[{ip}]"),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Response {
+    status: u16,
+    /// Names are lowercased.
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Response {
+    fn parse(raw: &[u8]) -> Option<Self> {
+        let end = find(raw, b"\r\n\r\n")?;
+        let head = String::from_utf8_lossy(&raw[..end]);
+        let mut lines = head.lines();
+        let status = lines
+            .next()?
+            .strip_prefix("HTTP/")?
+            .split_whitespace()
+            .nth(1)?
+            .parse()
+            .ok()?;
+        let headers: Vec<(String, String)> = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_owned()))
+            .collect();
+
+        let body = &raw[end + 4..];
+        let chunked = headers.iter().any(|(name, value)| {
+            name == "transfer-encoding" && value.to_ascii_lowercase().contains("chunked")
+        });
+        Some(Self {
+            status,
+            headers,
+            body: if chunked {
+                dechunk(body)
+            } else {
+                body.to_vec()
+            },
+        })
+    }
+
+    /// Whether `raw` holds a whole answer, so a server keeping the
+    /// connection open doesn't cost a read timeout.
+    fn is_complete(raw: &[u8]) -> bool {
+        let Some(response) = Self::parse(raw) else {
+            return false;
+        };
+        let body_len = raw.len() - find(raw, b"\r\n\r\n").unwrap_or(0) - 4;
+        match response.header("content-length") {
+            Some(length) => length.parse().is_ok_and(|length: usize| body_len >= length),
+            None => raw.ends_with(b"0\r\n\r\n") && response.header("transfer-encoding").is_some(),
+        }
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Undoes chunked transfer encoding, keeping what came before a malformed
+/// or missing chunk.
+fn dechunk(mut body: &[u8]) -> Vec<u8> {
+    let mut data = Vec::new();
+    while let Some(line_end) = find(body, b"\r\n") {
+        let size = String::from_utf8_lossy(&body[..line_end]);
+        let size = size.split(';').next().unwrap_or_default().trim();
+        let Ok(size) = usize::from_str_radix(size, 16) else {
+            break;
+        };
+        body = &body[line_end + 2..];
+        if size == 0 || body.len() < size {
+            data.extend_from_slice(&body[..size.min(body.len())]);
+            break;
+        }
+        data.extend_from_slice(&body[..size]);
+        body = body.get(size + 2..).unwrap_or_default();
+    }
+    data
+}
+
+fn page_title(body: &[u8]) -> Option<String> {
+    let body = String::from_utf8_lossy(body);
+    let title = TITLE.captures(&body)?.get(1)?.as_str();
+    let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+    let title = decode_entities(&title);
+    (!title.is_empty()).then(|| title.chars().take(MAX_TITLE).collect())
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn auth_realm(challenge: &str) -> Option<String> {
+    let realm = REALM.captures(challenge)?;
+    realm
+        .get(1)
+        .or_else(|| realm.get(2))
+        .map(|realm| realm.as_str().to_owned())
+}
+
+/// The hash Shodan computes: murmur3 (seed 0, as a signed integer) of the
+/// base64 encoding with a newline after every 76 characters and at the end.
+fn favicon_hash(icon: &[u8]) -> i32 {
+    let encoded = STANDARD.encode(icon);
+    let mut wrapped = Vec::with_capacity(encoded.len() + encoded.len() / 76 + 1);
+    for line in encoded.as_bytes().chunks(76) {
+        wrapped.extend_from_slice(line);
+        wrapped.push(b'\n');
+    }
+    mmh3(&wrapped)
+}
+
+fn mmh3(data: &[u8]) -> i32 {
+    let hash = murmur3::murmur3_32(&mut std::io::Cursor::new(data), 0)
+        .expect("reading from memory cannot fail");
+    hash as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dechunk, favicon_hash, mmh3, HttpProber, Scheme, Target};
+    use async_std::task::block_on;
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpListener};
+    use std::thread;
+    use std::time::Duration;
+
+    /// Serves every connection with `answer`, which gets the request path.
+    fn serve(answer: fn(&str) -> Vec<u8>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let mut request = [0; 1024];
+                let len = stream.read(&mut request).unwrap_or(0);
+                let request = String::from_utf8_lossy(&request[..len]);
+                let path = request.split_whitespace().nth(1).unwrap_or("/").to_owned();
+                let _ = stream.write_all(&answer(&path));
+            }
+        });
+        addr
+    }
+
+    fn prober() -> HttpProber {
+        HttpProber::new().with_timeout(Duration::from_millis(500))
+    }
+
+    #[test]
+    fn follows_redirects_to_the_login_page() {
+        let addr = serve(|path| {
+            match path {
+            "/" => b"HTTP/1.1 302 Found\r\nLocation: /doc/page/login.asp\r\nContent-Length: 0\r\n\r\n".to_vec(),
+            "/doc/page/login.asp" => b"HTTP/1.1 200 OK\r\nServer: webserver\r\nX-Powered-By: PHP/7.4\r\nTransfer-Encoding: chunked\r\n\r\n1d\r\n<html><title> NVR &amp; Login\r\nf\r\n</title></html>\r\n0\r\n\r\n".to_vec(),
+            "/favicon.ico" => b"HTTP/1.1 200 OK\r\nContent-Type: image/x-icon\r\nContent-Length: 3\r\n\r\nfoo".to_vec(),
+            _ => b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n".to_vec(),
+        }
+        });
+
+        let found = block_on(prober().fingerprint(addr, Scheme::Http)).unwrap();
+        assert_eq!(found.status, 200);
+        assert_eq!(found.url, format!("http://{addr}/doc/page/login.asp"));
+        assert_eq!(found.redirects, [found.url.as_str()]);
+        assert_eq!(found.title.as_deref(), Some("NVR & Login"));
+        assert_eq!(found.server.as_deref(), Some("webserver"));
+        assert_eq!(found.powered_by.as_deref(), Some("PHP/7.4"));
+        assert_eq!(found.favicon_hash, Some(favicon_hash(b"foo")));
+    }
+
+    #[test]
+    fn reads_the_auth_realm() {
+        let addr = serve(|_| {
+            b"HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Digest realm=\"DS-2CD2042WD\", nonce=\"x\"\r\nContent-Length: 0\r\n\r\n".to_vec()
+        });
+
+        let found = block_on(prober().fingerprint(addr, Scheme::Http)).unwrap();
+        assert_eq!(found.status, 401);
+        assert_eq!(found.auth_realm.as_deref(), Some("DS-2CD2042WD"));
+        assert_eq!(found.favicon_hash, None);
+    }
+
+    #[test]
+    fn stops_at_redirects_leaving_the_host() {
+        let addr = serve(|_| {
+            b"HTTP/1.1 301 Moved\r\nLocation: https://cloud.example.com/login\r\nContent-Length: 0\r\n\r\n".to_vec()
+        });
+
+        let found = block_on(prober().fingerprint(addr, Scheme::Http)).unwrap();
+        assert_eq!(found.status, 301);
+        assert_eq!(found.redirects, ["https://cloud.example.com/login"]);
+    }
+
+    #[test]
+    fn joins_locations_like_a_browser() {
+        let page = Target {
+            scheme: Scheme::Http,
+            host: "10.0.0.5".to_owned(),
+            port: 8080,
+            path: "/cgi-bin/index.cgi".to_owned(),
+        };
+        assert_eq!(
+            page.join("login.cgi").unwrap().to_string(),
+            "http://10.0.0.5:8080/cgi-bin/login.cgi"
+        );
+        assert_eq!(page.join("/").unwrap().to_string(), "http://10.0.0.5:8080/");
+        assert_eq!(
+            page.join("https://10.0.0.5/").unwrap().to_string(),
+            "https://10.0.0.5/"
+        );
+        assert_eq!(
+            page.join("//[fe80::1]:8443?x=1").unwrap().to_string(),
+            "http://[fe80::1]:8443/?x=1"
+        );
+        assert_eq!(page.join("ftp://10.0.0.5/"), None);
+    }
+
+    #[test]
+    fn dechunks_bodies() {
+        assert_eq!(
+            dechunk(b"4\r\nWiki\r\n5;ext\r\npedia\r\n0\r\n\r\n"),
+            b"Wikipedia"
+        );
+        assert_eq!(dechunk(b"4\r\nWi"), b"Wi");
+    }
+
+    #[test]
+    fn favicon_hash_matches_shodan() {
+        // mmh3.hash("foo") in Python
+        assert_eq!(mmh3(b"foo"), -156_908_512);
+        // mmh3.hash(codecs.encode(b"foo", "base64")), which hashes "Zm9v\n"
+        assert_eq!(favicon_hash(b"foo"), mmh3(b"Zm9v\n"));
+    }
+}
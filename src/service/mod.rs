@@ -0,0 +1,99 @@
+//! Service and version detection.
+//!
+//! Open ports are identified the way nmap does it: probes from a database in
+//! the nmap-service-probes format are sent one after the other, and whatever
+//! comes back is matched against the patterns listed under each probe. A
+//! small database is built in, the full one from an nmap install can be
+//! loaded with `ServiceProbes::from_file`.
+//!
+//! Ports that speak TLS get a handshake instead, see `TlsInspector`, and
+//! web interfaces are fingerprinted by `HttpProber`.
+mod detect;
+mod http;
+mod probes;
+mod tls;
+
+pub use detect::{ServiceDetector, DEFAULT_INTENSITY};
+pub use http::{web_scheme, HttpFingerprint, HttpProber, Scheme, HTTPS_PORTS};
+pub(crate) use probes::unescape;
+pub use probes::{Probe, ProbeProtocol, ServiceProbes};
+pub use tls::{
+    is_tls_port, CertificateInfo, SniBehaviour, TlsInfo, TlsInspector, DEFAULT_EXPIRY_WARNING_DAYS,
+};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What a match line of the probe database told about a port.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceMatch {
+    /// nmap's service name, such as `ssh`, `http` or `domain`.
+    pub service: String,
+    pub product: Option<String>,
+    pub version: Option<String>,
+    pub info: Option<String>,
+    pub hostname: Option<String>,
+    pub os: Option<String>,
+    pub device_type: Option<String>,
+    pub cpe: Vec<String>,
+    /// A softmatch only names the service, not the software behind it.
+    pub soft: bool,
+}
+
+impl ServiceMatch {
+    /// Product, version and extra info, like the VERSION column of nmap.
+    pub fn version_string(&self) -> Option<String> {
+        let mut parts: Vec<String> = [&self.product, &self.version]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect();
+        if let Some(info) = &self.info {
+            parts.push(format!("({info})"));
+        }
+        (!parts.is_empty()).then(|| parts.join(" "))
+    }
+}
+
+impl fmt::Display for ServiceMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.service)?;
+        if let Some(version) = self.version_string() {
+            write!(f, " {version}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the first hard match, or the first softmatch if there is none.
+fn best_match(matches: impl IntoIterator<Item = ServiceMatch>) -> Option<ServiceMatch> {
+    let mut soft = None;
+    for found in matches {
+        if !found.soft {
+            return Some(found);
+        }
+        soft.get_or_insert(found);
+    }
+    soft
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ServiceMatch;
+
+    #[test]
+    fn display_reads_like_nmap() {
+        let found = ServiceMatch {
+            service: "ssh".to_owned(),
+            product: Some("OpenSSH".to_owned()),
+            version: Some("9.6p1".to_owned()),
+            info: Some("protocol 2.0".to_owned()),
+            hostname: None,
+            os: None,
+            device_type: None,
+            cpe: vec![],
+            soft: false,
+        };
+        assert_eq!(found.to_string(), "ssh OpenSSH 9.6p1 (protocol 2.0)");
+    }
+}
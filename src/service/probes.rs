@@ -0,0 +1,680 @@
+//! Parser for databases in the nmap-service-probes format.
+//!
+//! The format is described at https://nmap.org/book/vscan-fileformat.html.
+//! Directives that don't matter for version detection are ignored, so the
+//! file shipped with nmap loads as is.
+use super::{best_match, ServiceMatch};
+use crate::scanner::Banner;
+use anyhow::{anyhow, bail, Result};
+use log::debug;
+use once_cell::sync::Lazy;
+use regex::bytes::{Captures, Regex};
+use std::fs;
+use std::iter;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+static EMBEDDED: Lazy<Arc<ServiceProbes>> = Lazy::new(|| {
+    let probes = ServiceProbes::parse(include_str!("../../nmap-service-probes"))
+        .expect("the embedded service probes are valid");
+    Arc::new(probes)
+});
+
+/// Rarity of probes that don't declare one.
+const DEFAULT_RARITY: u8 = 5;
+/// How long nmap waits for an answer when a probe has no `totalwaitms`.
+const DEFAULT_TOTAL_WAIT: Duration = Duration::from_millis(5000);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProbeProtocol {
+    Tcp,
+    Udp,
+}
+
+/// A payload to send, and the patterns that recognise the answers to it.
+#[derive(Debug)]
+pub struct Probe {
+    pub protocol: ProbeProtocol,
+    pub name: String,
+    pub payload: Vec<u8>,
+    /// Ports this probe is most likely to get an answer on.
+    pub ports: Vec<RangeInclusive<u16>>,
+    /// The same, for services wrapped in TLS.
+    pub ssl_ports: Vec<RangeInclusive<u16>>,
+    /// From 1, worth sending everywhere, to 9, hardly ever answered.
+    pub rarity: u8,
+    pub total_wait: Duration,
+    /// Probes whose patterns are also tried on the answers to this one.
+    pub fallback: Vec<String>,
+    matches: Vec<Match>,
+}
+
+impl Probe {
+    fn new(protocol: ProbeProtocol, name: &str, payload: Vec<u8>) -> Self {
+        Self {
+            protocol,
+            name: name.to_owned(),
+            payload,
+            ports: vec![],
+            ssl_ports: vec![],
+            rarity: DEFAULT_RARITY,
+            total_wait: DEFAULT_TOTAL_WAIT,
+            fallback: vec![],
+            matches: vec![],
+        }
+    }
+
+    /// The NULL probe sends nothing and waits for a greeting.
+    pub fn is_null(&self) -> bool {
+        self.protocol == ProbeProtocol::Tcp && self.payload.is_empty()
+    }
+
+    fn targets(&self, port: u16) -> bool {
+        self.ports.iter().any(|range| range.contains(&port))
+    }
+
+    /// Whether one of the patterns of this probe may recognise `service`.
+    pub fn can_identify(&self, service: &str) -> bool {
+        self.matches.iter().any(|m| m.service == service)
+    }
+}
+
+/// A `match` or `softmatch` line.
+#[derive(Debug)]
+struct Match {
+    service: String,
+    pattern: Regex,
+    soft: bool,
+    template: VersionTemplate,
+}
+
+impl Match {
+    fn apply(&self, response: &[u8]) -> Option<ServiceMatch> {
+        let captures = self.pattern.captures(response)?;
+        let fill = |field: &Option<String>| {
+            field
+                .as_deref()
+                .and_then(|template| substitute(template, &captures))
+        };
+        let template = &self.template;
+        Some(ServiceMatch {
+            service: self.service.clone(),
+            product: fill(&template.product),
+            version: fill(&template.version),
+            info: fill(&template.info),
+            hostname: fill(&template.hostname),
+            os: fill(&template.os),
+            device_type: fill(&template.device_type),
+            cpe: template
+                .cpe
+                .iter()
+                .filter_map(|cpe| substitute(cpe, &captures))
+                .map(|cpe| format!("cpe:/{cpe}"))
+                .collect(),
+            soft: self.soft,
+        })
+    }
+}
+
+/// The `p/ v/ i/ h/ o/ d/` and `cpe:/` fields of a match line. They may
+/// refer to the capture groups of the pattern.
+#[derive(Debug, Default)]
+struct VersionTemplate {
+    product: Option<String>,
+    version: Option<String>,
+    info: Option<String>,
+    hostname: Option<String>,
+    os: Option<String>,
+    device_type: Option<String>,
+    cpe: Vec<String>,
+}
+
+/// A parsed service probe database.
+#[derive(Debug, Default)]
+pub struct ServiceProbes {
+    probes: Vec<Probe>,
+    /// Ports never to probe. `None` excludes the port for both protocols.
+    excluded: Vec<(Option<ProbeProtocol>, RangeInclusive<u16>)>,
+}
+
+impl ServiceProbes {
+    /// The probes built into the binary, from `nmap-service-probes` at the
+    /// root of the repository.
+    pub fn embedded() -> Arc<Self> {
+        EMBEDDED.clone()
+    }
+
+    /// Loads a database such as `/usr/share/nmap/nmap-service-probes`.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let data = fs::read(path)?;
+        Self::parse(&String::from_utf8_lossy(&data))
+    }
+
+    /// Patterns the regex crate can't handle, such as backreferences, are
+    /// skipped rather than failing the whole database.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut probes = Self::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            probes
+                .parse_line(line)
+                .map_err(|e| anyhow!("line {}: {}", index + 1, e))?;
+        }
+        Ok(probes)
+    }
+
+    fn parse_line(&mut self, line: &str) -> Result<()> {
+        let (directive, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let rest = rest.trim();
+        match directive {
+            "Probe" => {
+                self.probes.push(parse_probe(rest)?);
+                return Ok(());
+            }
+            "Exclude" => {
+                self.excluded.extend(parse_port_list(rest)?);
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let probe = self
+            .probes
+            .last_mut()
+            .ok_or_else(|| anyhow!("{} before the first Probe", directive))?;
+        match directive {
+            "match" | "softmatch" => {
+                if let Some(m) = parse_match(rest, directive == "softmatch")? {
+                    probe.matches.push(m);
+                }
+            }
+            "ports" => probe.ports = parse_ports(rest)?,
+            "sslports" => probe.ssl_ports = parse_ports(rest)?,
+            "rarity" => probe.rarity = rest.parse()?,
+            "totalwaitms" => probe.total_wait = Duration::from_millis(rest.parse()?),
+            "fallback" => {
+                probe.fallback = rest.split(',').map(|name| name.trim().to_owned()).collect()
+            }
+            // tcpwrappedms and whatever newer nmap versions add.
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn probes(&self) -> &[Probe] {
+        &self.probes
+    }
+
+    pub fn probe(&self, name: &str, protocol: ProbeProtocol) -> Option<&Probe> {
+        self.probes
+            .iter()
+            .find(|probe| probe.protocol == protocol && probe.name == name)
+    }
+
+    pub fn is_excluded(&self, port: u16, protocol: ProbeProtocol) -> bool {
+        self.excluded.iter().any(|(excluded, range)| {
+            excluded.is_none_or(|excluded| excluded == protocol) && range.contains(&port)
+        })
+    }
+
+    /// The probes to send to `port`, in the order nmap sends them: the NULL
+    /// probe, then the probes listing the port whatever their rarity, then
+    /// the others that are no rarer than `intensity`.
+    pub fn probes_for(&self, port: u16, protocol: ProbeProtocol, intensity: u8) -> Vec<&Probe> {
+        let (null, rest): (Vec<&Probe>, Vec<&Probe>) = self
+            .probes
+            .iter()
+            .filter(|probe| probe.protocol == protocol)
+            .partition(|probe| probe.is_null());
+        let (listed, others): (Vec<&Probe>, Vec<&Probe>) =
+            rest.into_iter().partition(|probe| probe.targets(port));
+        null.into_iter()
+            .chain(listed)
+            .chain(others.into_iter().filter(|probe| probe.rarity <= intensity))
+            .collect()
+    }
+
+    /// Recognises the answer to `probe`. Its own patterns are tried first,
+    /// then those of its fallbacks and, over TCP, those of the NULL probe,
+    /// since a service may greet us before reading what we sent.
+    pub fn match_response(&self, probe: &Probe, response: &[u8]) -> Option<ServiceMatch> {
+        let fallbacks = probe
+            .fallback
+            .iter()
+            .filter_map(|name| self.probe(name, probe.protocol));
+        let null = self
+            .probe("NULL", ProbeProtocol::Tcp)
+            .filter(|null| probe.protocol == ProbeProtocol::Tcp && null.name != probe.name);
+        let matches = iter::once(probe)
+            .chain(fallbacks)
+            .chain(null)
+            .flat_map(|candidate| &candidate.matches)
+            .filter_map(|m| m.apply(response));
+        best_match(matches)
+    }
+
+    /// Recognises a banner grabbed by the scanner. When the service only
+    /// spoke after the nudge, the banner answers blank lines or an HTTP
+    /// request and is matched like the answers to those probes.
+    pub fn match_banner(&self, banner: &Banner) -> Option<ServiceMatch> {
+        let names: &[&str] = if banner.nudged {
+            &["GenericLines", "GetRequest"]
+        } else {
+            &["NULL"]
+        };
+        let matches = names
+            .iter()
+            .filter_map(|name| self.probe(name, ProbeProtocol::Tcp))
+            .filter_map(|probe| self.match_response(probe, &banner.data));
+        best_match(matches)
+    }
+}
+
+/// `TCP GetRequest q|GET / HTTP/1.0\r\n\r\n|`
+fn parse_probe(line: &str) -> Result<Probe> {
+    let mut parts = line.splitn(3, ' ');
+    let protocol = match parts.next() {
+        Some("TCP") => ProbeProtocol::Tcp,
+        Some("UDP") => ProbeProtocol::Udp,
+        other => bail!("unknown probe protocol {:?}", other),
+    };
+    let name = parts
+        .next()
+        .ok_or_else(|| anyhow!("probe without a name"))?;
+    let payload = parts
+        .next()
+        .and_then(|payload| payload.strip_prefix('q'))
+        .ok_or_else(|| anyhow!("probe {} without a payload", name))?;
+    let (payload, _) = delimited(payload)?;
+    Ok(Probe::new(protocol, name, unescape(payload)?))
+}
+
+/// `ssh m|^SSH-([\d.]+)-OpenSSH_([\w.]+)|s p/OpenSSH/ v/$2/ cpe:/a:openbsd:openssh:$2/`
+///
+/// Returns `None` for patterns the regex crate can't compile.
+fn parse_match(line: &str, soft: bool) -> Result<Option<Match>> {
+    let (service, rest) = line
+        .split_once(' ')
+        .ok_or_else(|| anyhow!("match without a pattern"))?;
+    let rest = rest
+        .trim_start()
+        .strip_prefix('m')
+        .ok_or_else(|| anyhow!("match for {} without a pattern", service))?;
+    let (pattern, rest) = delimited(rest)?;
+    let (flags, rest) = rest.split_at(rest.find(' ').unwrap_or(rest.len()));
+    let template = parse_template(rest)?;
+
+    match compile(pattern, flags) {
+        Ok(pattern) => Ok(Some(Match {
+            service: service.to_owned(),
+            pattern,
+            soft,
+            template,
+        })),
+        Err(e) => {
+            debug!(
+                "Skipping a {} match the regex crate can't handle: {}",
+                service, e
+            );
+            Ok(None)
+        }
+    }
+}
+
+fn parse_template(mut rest: &str) -> Result<VersionTemplate> {
+    let mut template = VersionTemplate::default();
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            return Ok(template);
+        }
+        if let Some(cpe) = rest.strip_prefix("cpe:") {
+            let (value, after) = delimited(cpe)?;
+            template.cpe.push(value.to_owned());
+            // The `a` flag tells nmap to append, the order is kept anyway.
+            rest = after.strip_prefix('a').unwrap_or(after);
+            continue;
+        }
+
+        let mut chars = rest.chars();
+        let field = chars.next().unwrap_or_default();
+        let (value, after) = delimited(chars.as_str())?;
+        let slot = match field {
+            'p' => &mut template.product,
+            'v' => &mut template.version,
+            'i' => &mut template.info,
+            'h' => &mut template.hostname,
+            'o' => &mut template.os,
+            'd' => &mut template.device_type,
+            _ => bail!("unknown version field {}", field),
+        };
+        *slot = Some(value.to_owned());
+        rest = after;
+    }
+}
+
+/// Splits `|text|rest` after the closing delimiter, which is whatever
+/// character opens the text.
+fn delimited(s: &str) -> Result<(&str, &str)> {
+    let delimiter = s
+        .chars()
+        .next()
+        .ok_or_else(|| anyhow!("missing delimiter"))?;
+    let body = &s[delimiter.len_utf8()..];
+    let end = body
+        .find(delimiter)
+        .ok_or_else(|| anyhow!("unterminated {}...{}", delimiter, delimiter))?;
+    Ok((&body[..end], &body[end + delimiter.len_utf8()..]))
+}
+
+/// Parses `21,80-85` lists, as well as the `T:9100-9107,U:53` ones of
+/// Exclude lines, where a protocol prefix holds until the next one.
+fn parse_port_list(list: &str) -> Result<Vec<(Option<ProbeProtocol>, RangeInclusive<u16>)>> {
+    let mut protocol = None;
+    list.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            let item = if let Some(item) = item.strip_prefix("T:") {
+                protocol = Some(ProbeProtocol::Tcp);
+                item
+            } else if let Some(item) = item.strip_prefix("U:") {
+                protocol = Some(ProbeProtocol::Udp);
+                item
+            } else {
+                item
+            };
+            let range = match item.split_once('-') {
+                Some((start, end)) => start.parse()?..=end.parse()?,
+                None => {
+                    let port = item.parse()?;
+                    port..=port
+                }
+            };
+            Ok((protocol, range))
+        })
+        .collect()
+}
+
+fn parse_ports(list: &str) -> Result<Vec<RangeInclusive<u16>>> {
+    Ok(parse_port_list(list)?
+        .into_iter()
+        .map(|(_, range)| range)
+        .collect())
+}
+
+/// Decodes the C style escapes of probe payloads.
+pub(crate) fn unescape(payload: &str) -> Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(payload.len());
+    let mut chars = payload.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        let byte = match chars.next() {
+            Some('0') => 0,
+            Some('a') => 0x07,
+            Some('b') => 0x08,
+            Some('f') => 0x0c,
+            Some('n') => b'\n',
+            Some('r') => b'\r',
+            Some('t') => b'\t',
+            Some('v') => 0x0b,
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+                u8::from_str_radix(&hex, 16).map_err(|_| anyhow!("bad escape \\x{}", hex))?
+            }
+            Some(c) if c.is_ascii() => c as u8,
+            _ => bail!("bad escape in payload"),
+        };
+        bytes.push(byte);
+    }
+    Ok(bytes)
+}
+
+/// nmap patterns are PCRE matched against raw bytes, so Unicode is turned
+/// off: `.` and `\xNN` then stand for bytes rather than characters.
+fn compile(pattern: &str, flags: &str) -> Result<Regex, regex::Error> {
+    let enabled: String = flags
+        .chars()
+        .filter(|flag| matches!(flag, 's' | 'i'))
+        .collect();
+    let mut translated = format!("(?{enabled}-u)");
+    // The regex crate has no octal escapes, `\0` is common for NUL bytes.
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            translated.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('0') => translated.push_str("\\x00"),
+            Some(next) => {
+                translated.push('\\');
+                translated.push(next);
+            }
+            None => translated.push('\\'),
+        }
+    }
+    Regex::new(&translated)
+}
+
+/// Fills in the `$1` references to capture groups and the `$P()`,
+/// `$SUBST()` and `$I()` helpers. Returns `None` when nothing is left.
+fn substitute(template: &str, captures: &Captures) -> Option<String> {
+    let group = |index: &str| {
+        index
+            .trim()
+            .parse()
+            .ok()
+            .and_then(|index| captures.get(index))
+            .map_or(&[][..], |group| group.as_bytes())
+    };
+
+    let mut filled = String::new();
+    let mut rest = template;
+    while let Some(at) = rest.find('$') {
+        filled.push_str(&rest[..at]);
+        rest = &rest[at + 1..];
+
+        if rest.starts_with(|c: char| c.is_ascii_digit()) {
+            filled.push_str(&String::from_utf8_lossy(group(&rest[..1])));
+            rest = &rest[1..];
+        } else if let Some((name, args, after)) = helper_call(rest) {
+            let arg = |index: usize| args.get(index).copied().unwrap_or_default();
+            match name {
+                // Only the printable characters.
+                "P" => filled.extend(
+                    group(arg(0))
+                        .iter()
+                        .filter(|byte| byte.is_ascii_graphic() || **byte == b' ')
+                        .map(|byte| *byte as char),
+                ),
+                "SUBST" => {
+                    filled.push_str(&String::from_utf8_lossy(group(arg(0))).replace(arg(1), arg(2)))
+                }
+                // An unsigned integer, `>` for big endian and `<` for little endian.
+                "I" => {
+                    let bytes = group(arg(0)).iter().take(8);
+                    let value = if arg(1) == "<" {
+                        bytes
+                            .rev()
+                            .fold(0u64, |value, byte| value << 8 | u64::from(*byte))
+                    } else {
+                        bytes.fold(0u64, |value, byte| value << 8 | u64::from(*byte))
+                    };
+                    filled.push_str(&value.to_string());
+                }
+                _ => {}
+            }
+            rest = after;
+        } else {
+            filled.push('$');
+        }
+    }
+    filled.push_str(rest);
+
+    let filled = filled.trim();
+    (!filled.is_empty()).then(|| filled.to_owned())
+}
+
+/// Splits `NAME(a,"b")rest` into the helper name, its arguments and the rest.
+fn helper_call(s: &str) -> Option<(&str, Vec<&str>, &str)> {
+    let open = s.find('(')?;
+    let name = &s[..open];
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    let close = open + s[open..].find(')')?;
+    let args = s[open + 1..close]
+        .split(',')
+        .map(|arg| arg.trim().trim_matches('"'))
+        .collect();
+    Some((name, args, &s[close + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProbeProtocol, ServiceProbes};
+    use crate::scanner::Banner;
+
+    const DATABASE: &str = r#"
+Exclude T:9100-9107,U:30000
+Probe TCP NULL q||
+match ssh m|^SSH-([\d.]+)-OpenSSH[_-]([\w._-]+)\r?\n| p/OpenSSH/ v/$2/ i/protocol $1/ cpe:/a:openbsd:openssh:$2/
+softmatch ftp m|^220[- ]|
+match mysql m|^.\0\0\0\x0a(\d[\d.]+)\0|s p/MySQL/ v/$1/
+match backref m|^(a)\1| p/skipped/
+Probe TCP GetRequest q|GET / HTTP/1.0\r\n\r\n|
+rarity 1
+ports 80,8000-8010
+match http m|^HTTP/1\.[01] \d+ .*\r\nServer: ([^\r\n]+)\r\n|si p/$P(1)/
+softmatch http m|^HTTP/1\.[01]|
+Probe TCP Rare q|x|
+rarity 9
+ports 1234
+Probe UDP DNS q|\0\x06\x01|
+"#;
+
+    fn database() -> ServiceProbes {
+        ServiceProbes::parse(DATABASE).unwrap()
+    }
+
+    #[test]
+    fn embedded_database_parses() {
+        let probes = ServiceProbes::embedded();
+        assert!(probes.probe("NULL", ProbeProtocol::Tcp).is_some());
+        assert!(probes.probe("GetRequest", ProbeProtocol::Tcp).is_some());
+        assert!(probes
+            .probe("DNSVersionBindReq", ProbeProtocol::Udp)
+            .is_some());
+    }
+
+    #[test]
+    fn payloads_are_unescaped() {
+        let probes = database();
+        let get = probes.probe("GetRequest", ProbeProtocol::Tcp).unwrap();
+        assert_eq!(get.payload, b"GET / HTTP/1.0\r\n\r\n");
+        let dns = probes.probe("DNS", ProbeProtocol::Udp).unwrap();
+        assert_eq!(dns.payload, [0, 6, 1]);
+    }
+
+    #[test]
+    fn version_fields_come_from_captures() {
+        let probes = database();
+        let null = probes.probe("NULL", ProbeProtocol::Tcp).unwrap();
+
+        let found = probes
+            .match_response(null, b"SSH-2.0-OpenSSH_9.6p1\r\n")
+            .unwrap();
+        assert_eq!(found.service, "ssh");
+        assert_eq!(found.product.as_deref(), Some("OpenSSH"));
+        assert_eq!(found.version.as_deref(), Some("9.6p1"));
+        assert_eq!(found.info.as_deref(), Some("protocol 2.0"));
+        assert_eq!(found.cpe, ["cpe:/a:openbsd:openssh:9.6p1"]);
+        assert!(!found.soft);
+    }
+
+    #[test]
+    fn patterns_match_raw_bytes() {
+        let probes = database();
+        let null = probes.probe("NULL", ProbeProtocol::Tcp).unwrap();
+
+        let found = probes
+            .match_response(null, b"\x4a\0\0\0\x0a8.0.36\0\xff\xfe")
+            .unwrap();
+        assert_eq!(found.version.as_deref(), Some("8.0.36"));
+    }
+
+    #[test]
+    fn hard_matches_beat_softmatches() {
+        let probes = database();
+        let get = probes.probe("GetRequest", ProbeProtocol::Tcp).unwrap();
+
+        let found = probes
+            .match_response(get, b"HTTP/1.1 200 OK\r\nserver: nginx\x01\r\n\r\n")
+            .unwrap();
+        assert_eq!(found.product.as_deref(), Some("nginx"));
+        assert!(!found.soft);
+
+        let found = probes.match_response(get, b"HTTP/1.0 404\r\n\r\n").unwrap();
+        assert_eq!(found.service, "http");
+        assert!(found.soft);
+    }
+
+    #[test]
+    fn other_probes_fall_back_to_null_matches() {
+        let probes = database();
+        let get = probes.probe("GetRequest", ProbeProtocol::Tcp).unwrap();
+
+        let found = probes.match_response(get, b"220 ready\r\n").unwrap();
+        assert_eq!(found.service, "ftp");
+    }
+
+    #[test]
+    fn probe_order_follows_ports_and_rarity() {
+        let probes = database();
+        let names = |port, intensity| {
+            probes
+                .probes_for(port, ProbeProtocol::Tcp, intensity)
+                .iter()
+                .map(|probe| probe.name.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(22, 7), ["NULL", "GetRequest"]);
+        assert_eq!(names(1234, 7), ["NULL", "Rare", "GetRequest"]);
+        assert_eq!(names(22, 0), ["NULL"]);
+    }
+
+    #[test]
+    fn exclusions_are_per_protocol() {
+        let probes = database();
+        assert!(probes.is_excluded(9100, ProbeProtocol::Tcp));
+        assert!(!probes.is_excluded(9100, ProbeProtocol::Udp));
+        assert!(probes.is_excluded(30000, ProbeProtocol::Udp));
+    }
+
+    #[test]
+    fn nudged_banners_are_matched_like_http_answers() {
+        let probes = ServiceProbes::embedded();
+        let banner = Banner {
+            data: b"HTTP/1.0 200 OK\r\nServer: nginx/1.24.0\r\n\r\n".to_vec(),
+            nudged: true,
+        };
+        let found = probes.match_banner(&banner).unwrap();
+        assert_eq!(found.to_string(), "http nginx 1.24.0");
+    }
+
+    #[test]
+    fn broken_lines_report_their_number() {
+        let error = ServiceProbes::parse("Probe TCP NULL q||\nmatch ssh").unwrap_err();
+        assert!(error.to_string().starts_with("line 2"));
+    }
+}
@@ -0,0 +1,501 @@
+//! TLS handshake inspection.
+//!
+//! Handshakes are done with rustls, which only speaks TLS 1.2 and 1.3: a
+//! server stuck on an older version shows up as a failed handshake.
+//! Certificates are not verified, they are collected so they can be reported.
+use crate::scanner::Source;
+use async_std::io;
+use chrono::{DateTime, Utc};
+use futures_rustls::TlsConnector;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{ring, CryptoProvider};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{
+    ClientConfig, ClientConnection, DigitallySignedStruct, ProtocolVersion, SignatureScheme,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use x509_parser::extensions::GeneralName;
+use x509_parser::oid_registry::{OID_SIG_ED25519, OID_SIG_ED448};
+use x509_parser::prelude::{FromDer, X509Certificate};
+use x509_parser::public_key::PublicKey;
+
+/// Certificates expiring within this many days get flagged.
+pub const DEFAULT_EXPIRY_WARNING_DAYS: i64 = 30;
+/// Offered to the server so the answer tells which one it prefers.
+const ALPN_PROTOCOLS: &[&[u8]] = &[b"h2", b"http/1.1"];
+
+/// What a TLS handshake with an open port revealed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TlsInfo {
+    /// Negotiated protocol version, such as `TLSv1.3`.
+    pub version: String,
+    /// Negotiated cipher suite, such as `TLS13_AES_256_GCM_SHA384`.
+    pub cipher_suite: String,
+    /// Application protocol the server picked among `h2` and `http/1.1`.
+    pub alpn: Option<String>,
+    pub sni: SniBehaviour,
+    /// The chain as the server sent it, leaf first.
+    pub certificates: Vec<CertificateInfo>,
+}
+
+impl TlsInfo {
+    /// The server's own certificate.
+    pub fn leaf(&self) -> Option<&CertificateInfo> {
+        self.certificates.first()
+    }
+
+    /// Certificates of the chain that expire before `now + days`, expired
+    /// ones included.
+    pub fn expiring(&self, now: DateTime<Utc>, days: i64) -> Vec<&CertificateInfo> {
+        self.certificates
+            .iter()
+            .filter(|certificate| certificate.days_left(now) < days)
+            .collect()
+    }
+}
+
+impl fmt::Display for TlsInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.version, self.cipher_suite)?;
+        if let Some(alpn) = &self.alpn {
+            write!(f, " alpn={alpn}")?;
+        }
+        if let Some(leaf) = self.leaf() {
+            write!(
+                f,
+                " cert=\"{}\" expires {}",
+                leaf.subject,
+                leaf.not_after.format("%Y-%m-%d")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// How the server reacted to Server Name Indication.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SniBehaviour {
+    /// No hostname was known to compare against.
+    NotTested,
+    /// The same certificate came back with and without SNI.
+    Ignored,
+    /// Sending the hostname got a different certificate.
+    SelectsCertificate,
+    /// The handshake only went through with SNI.
+    Required,
+}
+
+impl fmt::Display for SniBehaviour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SniBehaviour::NotTested => "not tested",
+            SniBehaviour::Ignored => "ignored",
+            SniBehaviour::SelectsCertificate => "selects certificate",
+            SniBehaviour::Required => "required",
+        })
+    }
+}
+
+/// The parts of an X.509 certificate worth reporting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertificateInfo {
+    pub subject: String,
+    pub issuer: String,
+    /// DNS names, IP addresses and emails from the subjectAltName extension.
+    pub sans: Vec<String>,
+    /// Hex bytes separated by colons.
+    pub serial: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    /// Algorithm and size, such as `RSA 2048` or `EC 256`.
+    pub key_type: String,
+    pub self_signed: bool,
+}
+
+impl CertificateInfo {
+    /// Parses a DER encoded certificate, `None` if it is malformed.
+    pub fn from_der(der: &[u8]) -> Option<Self> {
+        let (_, certificate) = X509Certificate::from_der(der).ok()?;
+        let validity = certificate.validity();
+        let sans = match certificate.subject_alternative_name() {
+            Ok(Some(extension)) => extension
+                .value
+                .general_names
+                .iter()
+                .filter_map(general_name)
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Some(Self {
+            subject: certificate.subject().to_string(),
+            issuer: certificate.issuer().to_string(),
+            sans,
+            serial: certificate.raw_serial_as_string(),
+            not_before: DateTime::from_timestamp(validity.not_before.timestamp(), 0)?,
+            not_after: DateTime::from_timestamp(validity.not_after.timestamp(), 0)?,
+            key_type: key_type(&certificate),
+            self_signed: certificate.subject().as_raw() == certificate.issuer().as_raw(),
+        })
+    }
+
+    /// Whole days until expiry, negative once expired.
+    pub fn days_left(&self, now: DateTime<Utc>) -> i64 {
+        (self.not_after - now).num_days()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.not_after <= now
+    }
+
+    /// "expired 3 days ago" or "expires in 12 days".
+    pub fn expiry_note(&self, now: DateTime<Utc>) -> String {
+        let days = self.days_left(now);
+        if self.is_expired(now) {
+            format!("expired {} days ago", -days)
+        } else {
+            format!("expires in {days} days")
+        }
+    }
+}
+
+fn general_name(name: &GeneralName<'_>) -> Option<String> {
+    match name {
+        GeneralName::DNSName(dns) => Some((*dns).to_owned()),
+        GeneralName::RFC822Name(email) => Some((*email).to_owned()),
+        GeneralName::IPAddress(bytes) => match bytes.len() {
+            4 => Some(Ipv4Addr::from(<[u8; 4]>::try_from(*bytes).ok()?).to_string()),
+            16 => Some(Ipv6Addr::from(<[u8; 16]>::try_from(*bytes).ok()?).to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn key_type(certificate: &X509Certificate<'_>) -> String {
+    let key = certificate.public_key();
+    match key.parsed() {
+        Ok(PublicKey::RSA(rsa)) => format!("RSA {}", rsa.key_size()),
+        Ok(PublicKey::EC(ec)) => format!("EC {}", ec.key_size()),
+        Ok(PublicKey::DSA(y)) => format!("DSA {}", y.len() * 8),
+        _ if key.algorithm.algorithm == OID_SIG_ED25519 => "Ed25519".to_owned(),
+        _ if key.algorithm.algorithm == OID_SIG_ED448 => "Ed448".to_owned(),
+        _ => key.algorithm.algorithm.to_id_string(),
+    }
+}
+
+/// Shakes hands with TLS services and reports what they negotiated.
+#[derive(Debug, Clone)]
+pub struct TlsInspector {
+    with_sni: Arc<ClientConfig>,
+    without_sni: Arc<ClientConfig>,
+    timeout: Duration,
+    source: Source,
+}
+
+impl Default for TlsInspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TlsInspector {
+    pub fn new() -> Self {
+        Self {
+            with_sni: client_config(true),
+            without_sni: client_config(false),
+            timeout: Duration::from_secs(3),
+            source: Source::default(),
+        }
+    }
+
+    /// Bounds each handshake, connect included.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Connects from `source`, like the scan that found the port.
+    pub fn with_source(mut self, source: Source) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Shakes hands with `socket`. With a `hostname`, a second handshake
+    /// sends it as SNI to see whether the server picks its certificate by
+    /// name, and that handshake is the one reported.
+    pub async fn inspect(&self, socket: SocketAddr, hostname: Option<&str>) -> io::Result<TlsInfo> {
+        let bare = self
+            .handshake(socket, &self.without_sni, ServerName::from(socket.ip()))
+            .await;
+        let Some(name) = hostname.and_then(|name| ServerName::try_from(name.to_owned()).ok())
+        else {
+            return bare;
+        };
+        if matches!(name, ServerName::IpAddress(_)) {
+            return bare;
+        }
+
+        match (bare, self.handshake(socket, &self.with_sni, name).await) {
+            (Ok(bare), Ok(mut named)) => {
+                named.sni = if bare.leaf() == named.leaf() {
+                    SniBehaviour::Ignored
+                } else {
+                    SniBehaviour::SelectsCertificate
+                };
+                Ok(named)
+            }
+            (Err(_), Ok(mut named)) => {
+                named.sni = SniBehaviour::Required;
+                Ok(named)
+            }
+            (Ok(bare), Err(_)) => Ok(bare),
+            (Err(_), Err(e)) => Err(e),
+        }
+    }
+
+    async fn handshake(
+        &self,
+        socket: SocketAddr,
+        config: &Arc<ClientConfig>,
+        name: ServerName<'static>,
+    ) -> io::Result<TlsInfo> {
+        let connector = TlsConnector::from(Arc::clone(config));
+        io::timeout(self.timeout, async {
+            let stream = self.source.connect(socket).await?;
+            let stream = connector.connect(name, stream).await?;
+            let (_, connection) = stream.get_ref();
+            Ok(session_info(connection))
+        })
+        .await
+    }
+}
+
+fn session_info(connection: &ClientConnection) -> TlsInfo {
+    let version = match connection.protocol_version() {
+        Some(ProtocolVersion::TLSv1_3) => "TLSv1.3".to_owned(),
+        Some(ProtocolVersion::TLSv1_2) => "TLSv1.2".to_owned(),
+        Some(other) => format!("{other:?}"),
+        None => String::new(),
+    };
+    TlsInfo {
+        version,
+        cipher_suite: connection
+            .negotiated_cipher_suite()
+            .map(|suite| format!("{:?}", suite.suite()))
+            .unwrap_or_default(),
+        alpn: connection
+            .alpn_protocol()
+            .map(|alpn| String::from_utf8_lossy(alpn).into_owned()),
+        sni: SniBehaviour::NotTested,
+        certificates: connection
+            .peer_certificates()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|der| CertificateInfo::from_der(der))
+            .collect(),
+    }
+}
+
+pub(crate) fn client_config(enable_sni: bool) -> Arc<ClientConfig> {
+    let provider = Arc::new(ring::default_provider());
+    let mut config = ClientConfig::builder_with_provider(Arc::clone(&provider))
+        .with_safe_default_protocol_versions()
+        .expect("ring supports TLS 1.2 and 1.3")
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate(provider)))
+        .with_no_client_auth();
+    config.enable_sni = enable_sni;
+    config.alpn_protocols = ALPN_PROTOCOLS.iter().map(|alpn| alpn.to_vec()).collect();
+    Arc::new(config)
+}
+
+/// Takes any certificate chain, expired and self-signed ones included. The
+/// handshake signatures are still checked so the session is a real one.
+#[derive(Debug)]
+struct AcceptAnyCertificate(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// Whether `port` is one where services start with a TLS handshake.
+pub fn is_tls_port(port: u16) -> bool {
+    crate::scanner::TLS_PORTS.contains(&port)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CertificateInfo, SniBehaviour, TlsInspector};
+    use async_std::task::block_on;
+    use chrono::{TimeZone, Utc};
+    use rustls::crypto::ring;
+    use rustls::pki_types::PrivateKeyDer;
+    use rustls::server::{ClientHello, ResolvesServerCert};
+    use rustls::sign::CertifiedKey;
+    use rustls::{ServerConfig, ServerConnection};
+    use std::net::{SocketAddr, TcpListener};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    /// Hands out `named` to clients sending SNI and `bare` to the others.
+    #[derive(Debug)]
+    struct Certificates {
+        bare: Option<Arc<CertifiedKey>>,
+        named: Arc<CertifiedKey>,
+    }
+
+    impl ResolvesServerCert for Certificates {
+        fn resolve(&self, hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+            match hello.server_name() {
+                Some(_) => Some(Arc::clone(&self.named)),
+                None => self.bare.clone(),
+            }
+        }
+    }
+
+    fn certified_key(name: &str) -> Arc<CertifiedKey> {
+        let rcgen::CertifiedKey { cert, key_pair } =
+            rcgen::generate_simple_self_signed(vec![name.to_owned()]).unwrap();
+        let key = PrivateKeyDer::Pkcs8(key_pair.serialize_der().into());
+        let signing_key = ring::default_provider()
+            .key_provider
+            .load_private_key(key)
+            .unwrap();
+        Arc::new(CertifiedKey::new(vec![cert.der().clone()], signing_key))
+    }
+
+    /// Runs a TLS server on loopback that answers ALPN with `h2`.
+    fn serve(certificates: Certificates) -> SocketAddr {
+        let mut config = ServerConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_no_client_auth()
+            .with_cert_resolver(Arc::new(certificates));
+        config.alpn_protocols = vec![b"h2".to_vec()];
+        let config = Arc::new(config);
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let mut connection = ServerConnection::new(Arc::clone(&config)).unwrap();
+                while connection.is_handshaking() {
+                    if connection.complete_io(&mut stream).is_err() {
+                        break;
+                    }
+                }
+                let _ = connection.complete_io(&mut stream);
+            }
+        });
+        addr
+    }
+
+    fn inspector() -> TlsInspector {
+        TlsInspector::new().with_timeout(Duration::from_secs(2))
+    }
+
+    #[test]
+    fn reports_the_session_and_the_chain() {
+        let key = certified_key("appliance.test");
+        let addr = serve(Certificates {
+            bare: Some(Arc::clone(&key)),
+            named: key,
+        });
+
+        let info = block_on(inspector().inspect(addr, None)).unwrap();
+        assert_eq!(info.version, "TLSv1.3");
+        assert!(info.cipher_suite.starts_with("TLS13_"));
+        assert_eq!(info.alpn.as_deref(), Some("h2"));
+        assert_eq!(info.sni, SniBehaviour::NotTested);
+
+        let leaf = info.leaf().unwrap();
+        assert_eq!(leaf.sans, ["appliance.test"]);
+        assert_eq!(leaf.key_type, "EC 256");
+        assert!(leaf.self_signed);
+    }
+
+    #[test]
+    fn tells_how_the_server_uses_sni() {
+        let addr = serve(Certificates {
+            bare: Some(certified_key("default.test")),
+            named: certified_key("named.test"),
+        });
+        let info = block_on(inspector().inspect(addr, Some("named.test"))).unwrap();
+        assert_eq!(info.sni, SniBehaviour::SelectsCertificate);
+        assert_eq!(info.leaf().unwrap().sans, ["named.test"]);
+
+        let addr = serve(Certificates {
+            bare: None,
+            named: certified_key("named.test"),
+        });
+        let info = block_on(inspector().inspect(addr, Some("named.test"))).unwrap();
+        assert_eq!(info.sni, SniBehaviour::Required);
+        assert!(block_on(inspector().inspect(addr, None)).is_err());
+    }
+
+    #[test]
+    fn expiry_is_counted_in_days() {
+        let mut params = rcgen::CertificateParams::new(vec!["old.test".to_owned()]).unwrap();
+        params.not_before = rcgen::date_time_ymd(2020, 1, 1);
+        params.not_after = rcgen::date_time_ymd(2021, 1, 1);
+        let key_pair = rcgen::KeyPair::generate().unwrap();
+        let der = params.self_signed(&key_pair).unwrap().der().to_vec();
+
+        let certificate = CertificateInfo::from_der(&der).unwrap();
+        let now = Utc.with_ymd_and_hms(2020, 12, 20, 0, 0, 0).unwrap();
+        assert_eq!(certificate.days_left(now), 12);
+        assert_eq!(certificate.expiry_note(now), "expires in 12 days");
+
+        let later = Utc.with_ymd_and_hms(2021, 1, 4, 0, 0, 0).unwrap();
+        assert!(certificate.is_expired(later));
+        assert_eq!(certificate.expiry_note(later), "expired 3 days ago");
+    }
+}
@@ -1,3 +1,5 @@
+use crate::service::HttpFingerprint;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::net::Ipv4Addr;
@@ -46,6 +48,8 @@ pub struct DeviceClassification {
     port_patterns: Vec<PortPattern>,
     hostname_patterns: Vec<HostnamePattern>,
     os_patterns: Vec<OSPattern>,
+    http_patterns: Vec<HttpPattern>,
+    favicon_patterns: Vec<FaviconPattern>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -70,6 +74,23 @@ struct OSPattern {
     confidence: f32,
 }
 
+/// Matched against the page title, `Server`, `X-Powered-By` and auth realm
+/// of a web interface.
+#[derive(Debug, Clone)]
+struct HttpPattern {
+    pattern: Regex,
+    device_type: DeviceType,
+    confidence: f32,
+}
+
+/// Shodan style favicon hashes, see `HttpFingerprint::favicon_hash`.
+#[derive(Debug, Clone, PartialEq)]
+struct FaviconPattern {
+    hash: i32,
+    device_type: DeviceType,
+    confidence: f32,
+}
+
 impl Default for DeviceClassification {
     fn default() -> Self {
         Self::new()
@@ -81,6 +102,8 @@ impl DeviceClassification {
         let mut port_patterns = Vec::new();
         let mut hostname_patterns = Vec::new();
         let mut os_patterns = Vec::new();
+        let mut http_patterns = Vec::new();
+        let mut favicon_patterns = Vec::new();
 
         port_patterns.push(PortPattern {
             ports: vec![22, 23, 80, 443],
@@ -255,10 +278,96 @@ impl DeviceClassification {
             confidence: 0.80,
         });
 
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)hikvision|app-webs|dnvrs-webs|dvrdvs-webs|\bDS-\d").unwrap(),
+            device_type: DeviceType::Camera,
+            confidence: 0.95,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)dahua|amcrest|reolink|\baxis\b|blue iris|network video recorder|\bnvr\b|ip camera|netcam").unwrap(),
+            device_type: DeviceType::Camera,
+            confidence: 0.90,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)synology|diskstation|\bqnap\b|\bqts\b|truenas|freenas|unraid|readynas|my cloud").unwrap(),
+            device_type: DeviceType::NAS,
+            confidence: 0.95,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)pfsense|opnsense|fortigate|fortinet|sonicwall|watchguard|sophos|checkpoint gaia").unwrap(),
+            device_type: DeviceType::Firewall,
+            confidence: 0.95,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)routeros|mikrotik|openwrt|\bluci\b|dd-wrt|edgeos|fritz!box|asuswrt|\brouter\b").unwrap(),
+            device_type: DeviceType::Router,
+            confidence: 0.90,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)procurve|prosafe|easy smart|managed switch|smart switch|\bswitch\b").unwrap(),
+            device_type: DeviceType::Switch,
+            confidence: 0.85,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)unifi|omada|ruckus|aruba instant|access point").unwrap(),
+            device_type: DeviceType::AccessPoint,
+            confidence: 0.85,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)laserjet|officejet|hp http server|epson|brother|canon http|xerox|lexmark|kyocera|ricoh|\bprinter\b|\bcups\b").unwrap(),
+            device_type: DeviceType::Printer,
+            confidence: 0.90,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)vmware esxi|proxmox|hyper-v|xenserver|xcp-ng").unwrap(),
+            device_type: DeviceType::VMHost,
+            confidence: 0.95,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)idrac|integrated lights-out|\bilo\b|supermicro|\bipmi\b").unwrap(),
+            device_type: DeviceType::Server,
+            confidence: 0.90,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)raritan|dominion kx|pikvm").unwrap(),
+            device_type: DeviceType::KVM,
+            confidence: 0.90,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)powerchute|network management card|cyberpower|\beaton\b").unwrap(),
+            device_type: DeviceType::UPS,
+            confidence: 0.85,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)philips hue").unwrap(),
+            device_type: DeviceType::Light,
+            confidence: 0.90,
+        });
+        http_patterns.push(HttpPattern {
+            pattern: Regex::new(r"(?i)home assistant|shelly|tasmota|esphome").unwrap(),
+            device_type: DeviceType::IoT,
+            confidence: 0.90,
+        });
+
+        // Hikvision web components
+        favicon_patterns.push(FaviconPattern {
+            hash: 999_357_577,
+            device_type: DeviceType::Camera,
+            confidence: 0.95,
+        });
+        // FortiOS SSL VPN portal
+        favicon_patterns.push(FaviconPattern {
+            hash: 945_408_572,
+            device_type: DeviceType::Firewall,
+            confidence: 0.95,
+        });
+
         DeviceClassification {
             port_patterns,
             hostname_patterns,
             os_patterns,
+            http_patterns,
+            favicon_patterns,
         }
     }
 
@@ -322,6 +431,43 @@ impl DeviceClassification {
         (best_device, best_score.min(1.0))
     }
 
+    /// Classifies a host from its web interfaces. A login page naming the
+    /// product is stronger evidence than any port heuristic, so a match here
+    /// should win over `classify`.
+    pub fn classify_http(&self, fingerprints: &[&HttpFingerprint]) -> Option<(DeviceType, f32)> {
+        let mut best: Option<(DeviceType, f32)> = None;
+        let mut consider = |device_type: DeviceType, confidence: f32| {
+            if best.is_none_or(|(_, score)| confidence > score) {
+                best = Some((device_type, confidence));
+            }
+        };
+
+        for fingerprint in fingerprints {
+            let fields = [
+                &fingerprint.title,
+                &fingerprint.server,
+                &fingerprint.powered_by,
+                &fingerprint.auth_realm,
+            ];
+            for pattern in &self.http_patterns {
+                if fields
+                    .iter()
+                    .filter_map(|field| field.as_deref())
+                    .any(|field| pattern.pattern.is_match(field))
+                {
+                    consider(pattern.device_type, pattern.confidence);
+                }
+            }
+            for pattern in &self.favicon_patterns {
+                if fingerprint.favicon_hash == Some(pattern.hash) {
+                    consider(pattern.device_type, pattern.confidence);
+                }
+            }
+        }
+
+        best
+    }
+
     pub fn is_likely_gateway(&self, ip: Ipv4Addr, _hostname: Option<&str>, _ports: &[u16]) -> bool {
         // Strict gateway detection based on IP convention
         let last_octet = ip.octets()[3];
@@ -379,6 +525,39 @@ mod tests {
         assert!(score > 0.0);
     }
 
+    fn fingerprint(title: &str, server: Option<&str>) -> HttpFingerprint {
+        HttpFingerprint {
+            url: "http://192.168.1.64/".to_string(),
+            status: 200,
+            server: server.map(str::to_string),
+            powered_by: None,
+            title: Some(title.to_string()),
+            redirects: Vec::new(),
+            auth_realm: None,
+            favicon_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_device_classification_http() {
+        let classifier = DeviceClassification::new();
+
+        let nvr = fingerprint("Login", Some("App-webs/"));
+        let (device, score) = classifier.classify_http(&[&nvr]).unwrap();
+        assert_eq!(device, DeviceType::Camera);
+        assert!(score > 0.9);
+
+        let nas = fingerprint("Synology DiskStation - nas01", Some("nginx"));
+        assert_eq!(classifier.classify_http(&[&nas]).unwrap().0, DeviceType::NAS);
+
+        let mut icon_only = fingerprint("", None);
+        icon_only.favicon_hash = Some(999_357_577);
+        assert_eq!(classifier.classify_http(&[&icon_only]).unwrap().0, DeviceType::Camera);
+
+        let plain = fingerprint("Welcome to nginx!", Some("nginx/1.24.0"));
+        assert_eq!(classifier.classify_http(&[&plain]), None);
+    }
+
     #[test]
     fn test_is_likely_gateway() {
         let classifier = DeviceClassification::new();
@@ -1,3 +1,8 @@
+use crate::scanner::{BannerGrab, CancelToken, ScanEvent, Scanner, Source};
+use crate::service::{
+    is_tls_port, web_scheme, HttpFingerprint, HttpProber, Scheme, ServiceMatch, ServiceProbes,
+    TlsInfo, TlsInspector,
+};
 use crate::topology::{DeviceType, GeoInfo};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -6,7 +11,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use tokio::net::TcpSocket;
+use futures::StreamExt;
 use tokio::net::UdpSocket;
 use tokio::time::timeout;
 
@@ -16,6 +21,9 @@ pub struct ArpEntry {
     pub mac: String,
     pub iface: String,
     pub entry_type: ArpEntryType,
+    /// How long the host took to answer our ARP request, for swept entries.
+    #[serde(default)]
+    pub response_time_us: Option<u64>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -23,6 +31,8 @@ pub enum ArpEntryType {
     Dynamic,
     Static,
     Unknown,
+    /// Answered an ARP request of ours rather than read from the OS cache.
+    Swept,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,12 +54,29 @@ pub struct DiscoveryResult {
 pub struct ProbedHost {
     pub ip: IpAddr,
     pub ports: Vec<u16>,
+    /// UDP ports that answered a probe.
+    #[serde(default)]
+    pub udp_ports: Vec<u16>,
     pub hostname: Option<String>,
     pub mac: Option<String>,
     pub vendor: Option<String>,
     pub os_info: Option<OSInfo>,
     pub device_type: DeviceType,
     pub is_gateway: bool,
+    /// Smoothed connect round-trip time, when the host answered at all.
+    pub latency_us: Option<u64>,
+    /// First line of what each open port said after connecting.
+    #[serde(default)]
+    pub banners: HashMap<u16, String>,
+    /// Services recognised from those banners.
+    #[serde(default)]
+    pub services: HashMap<u16, ServiceMatch>,
+    /// Handshakes with the open ports that speak TLS.
+    #[serde(default)]
+    pub tls: HashMap<u16, TlsInfo>,
+    /// What the web interfaces on the host look like.
+    #[serde(default)]
+    pub http: HashMap<u16, HttpFingerprint>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -78,33 +105,52 @@ const COMMON_PORTS: [u16; 20] = [
     27017,
 ];
 
+/// All of them have a payload in nmap-payloads, a service needs something
+/// it understands before it answers.
+const COMMON_UDP_PORTS: [u16; 5] = [53, 123, 137, 161, 1900];
+
+/// Discovery never looks at more addresses than this, whatever the subnet.
+const MAX_TARGETS: u128 = 256;
+
+/// How discovery scans the ports of the hosts in the subnet.
+///
+/// The ports go through the same `Scanner` as the command line, so silent
+/// ports are tried again, `max_rate` paces the probes over every host and
+/// IPv6 hosts are scanned like IPv4 ones.
+#[derive(Debug, Clone)]
+pub struct PortProbe {
+    pub tcp_ports: Vec<u16>,
+    /// Left out of the scan when empty.
+    pub udp_ports: Vec<u16>,
+    pub timeout: Duration,
+    /// How many times a port that does not answer is probed.
+    pub tries: u8,
+    /// Probes per second over the whole subnet, unlimited when `None`.
+    pub max_rate: Option<u32>,
+    /// How many ports are probed, and hosts looked at, at once.
+    pub max_concurrent: usize,
+}
+
+impl Default for PortProbe {
+    fn default() -> Self {
+        Self {
+            tcp_ports: COMMON_PORTS.to_vec(),
+            udp_ports: COMMON_UDP_PORTS.to_vec(),
+            timeout: Duration::from_millis(1000),
+            tries: 2,
+            max_rate: None,
+            max_concurrent: 128,
+        }
+    }
+}
+
 pub async fn get_arp_entries() -> Vec<ArpEntry> {
     let mut entries = Vec::new();
 
     #[cfg(target_os = "linux")]
     {
         if let Ok(content) = tokio::fs::read_to_string("/proc/net/arp").await {
-            for line in content.lines().skip(1) {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                if parts.len() >= 6 {
-                    if let Ok(ip) = parts[0].parse::<Ipv4Addr>() {
-                        let mac = parts[3];
-                        if mac != "00:00:00:00:00:00" && mac.len() == 17 {
-                            let entry_type = if parts[5] == "0x2" {
-                                ArpEntryType::Static
-                            } else {
-                                ArpEntryType::Dynamic
-                            };
-                            entries.push(ArpEntry {
-                                ip,
-                                mac: mac.to_uppercase(),
-                                iface: String::new(),
-                                entry_type,
-                            });
-                        }
-                    }
-                }
-            }
+            entries = parse_proc_net_arp(&content);
         }
     }
 
@@ -121,17 +167,22 @@ pub async fn get_arp_entries() -> Vec<ArpEntry> {
             .and_then(|o| String::from_utf8(o.stdout).ok());
 
         if let Some(content) = output {
+            // Entries are listed under "Interface: 192.168.1.10 --- 0xb"
+            let mut iface = String::new();
             for line in content.lines() {
                 let parts: Vec<&str> = line.split_whitespace().collect();
-                if parts.len() >= 2 {
+                if parts.first() == Some(&"Interface:") && parts.len() >= 2 {
+                    iface = parts[1].to_string();
+                } else if parts.len() >= 2 {
                     if let Ok(ip) = parts[0].parse::<Ipv4Addr>() {
                         let mac = parts[1];
                         if mac.contains('-') && mac.len() == 17 {
                             entries.push(ArpEntry {
                                 ip,
                                 mac: mac.to_uppercase(),
-                                iface: String::new(),
+                                iface: iface.clone(),
                                 entry_type: ArpEntryType::Dynamic,
+                                response_time_us: None,
                             });
                         }
                     }
@@ -160,11 +211,19 @@ pub async fn get_arp_entries() -> Vec<ArpEntry> {
                                 let mac_parts: Vec<&str> = mac_line.split_whitespace().collect();
                                 if let Some(mac) = mac_parts.first() {
                                     if mac.contains(':') && mac.len() == 17 {
+                                        // "... at 0:1a:2b:3c:4d:5e on en0 ifscope [ethernet]"
+                                        let iface = mac_parts
+                                            .iter()
+                                            .skip_while(|part| **part != "on")
+                                            .nth(1)
+                                            .map(|iface| iface.to_string())
+                                            .unwrap_or_default();
                                         entries.push(ArpEntry {
                                             ip,
                                             mac: mac.to_uppercase(),
-                                            iface: String::new(),
+                                            iface,
                                             entry_type: ArpEntryType::Dynamic,
+                                            response_time_us: None,
                                         });
                                     }
                                 }
@@ -179,11 +238,109 @@ pub async fn get_arp_entries() -> Vec<ArpEntry> {
     entries
 }
 
+/// Parses the kernel ARP table, whose columns are address, hardware type,
+/// flags, MAC, mask and device.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_proc_net_arp(content: &str) -> Vec<ArpEntry> {
+    // ATF_COM is set on every resolved entry, ATF_PERM only on static ones.
+    const ATF_COM: u32 = 0x2;
+    const ATF_PERM: u32 = 0x4;
+
+    let mut entries = Vec::new();
+    for line in content.lines().skip(1) {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() < 6 {
+            continue;
+        }
+        let Ok(ip) = parts[0].parse::<Ipv4Addr>() else {
+            continue;
+        };
+        let flags = u32::from_str_radix(parts[2].trim_start_matches("0x"), 16).unwrap_or(0);
+        let mac = parts[3];
+        if flags & ATF_COM == 0 || mac == "00:00:00:00:00:00" || mac.len() != 17 {
+            continue;
+        }
+        entries.push(ArpEntry {
+            ip,
+            mac: mac.to_uppercase(),
+            iface: parts[5].to_string(),
+            entry_type: if flags & ATF_PERM != 0 {
+                ArpEntryType::Static
+            } else {
+                ArpEntryType::Dynamic
+            },
+            response_time_us: None,
+        });
+    }
+    entries
+}
+
+/// Sends an ARP request to every address of `targets` and collects the
+/// replies, so that neighbours the OS never talked to show up too. Only
+/// `interface` is used when given, otherwise every target is asked on the
+/// interface it is directly attached to. Targets that are not on-link are
+/// skipped.
+///
+/// Needs root or `CAP_NET_RAW`.
+#[cfg(unix)]
+pub async fn sweep_arp(
+    targets: &[Ipv4Addr],
+    interface: Option<&str>,
+    timeout_duration: Duration,
+) -> std::io::Result<Vec<ArpEntry>> {
+    let groups = crate::scanner::group_by_interface(targets, interface);
+    let replies = tokio::task::spawn_blocking(move || {
+        let mut replies = Vec::new();
+        for (interface, targets) in &groups {
+            replies.extend(crate::scanner::arp_sweep(interface, targets, timeout_duration)?);
+        }
+        Ok::<_, std::io::Error>(replies)
+    })
+    .await
+    .map_err(std::io::Error::other)??;
+
+    Ok(replies
+        .into_iter()
+        .map(|reply| ArpEntry {
+            ip: reply.ip,
+            mac: reply.mac.to_string().to_uppercase(),
+            iface: reply.interface,
+            entry_type: ArpEntryType::Swept,
+            response_time_us: Some(reply.rtt.as_micros() as u64),
+        })
+        .collect())
+}
+
+#[cfg(not(unix))]
+pub async fn sweep_arp(
+    _targets: &[Ipv4Addr],
+    _interface: Option<&str>,
+    _timeout_duration: Duration,
+) -> std::io::Result<Vec<ArpEntry>> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "ARP sweeps need raw sockets",
+    ))
+}
+
+/// Adds the swept entries to those of the ARP cache. A fresh reply wins
+/// over what the cache says about the same address.
+fn merge_arp_entries(cached: Vec<ArpEntry>, swept: Vec<ArpEntry>) -> Vec<ArpEntry> {
+    let mut entries: Vec<ArpEntry> = cached
+        .into_iter()
+        .filter(|entry| !swept.iter().any(|fresh| fresh.ip == entry.ip))
+        .collect();
+    entries.extend(swept);
+    entries.sort_by_key(|entry| entry.ip);
+    entries
+}
+
 pub async fn trace_route(
     target: IpAddr,
     max_hops: u8,
     timeout_duration: Duration,
     cancel_flag: Arc<AtomicBool>,
+    source: &Source,
 ) -> TracerouteResult {
     let mut hops = Vec::new();
     let start_time = Instant::now();
@@ -191,7 +348,7 @@ pub async fn trace_route(
     let mut ttl: u8 = 1;
 
     while ttl <= max_hops && !cancel_flag.load(Ordering::Relaxed) {
-        let hop = perform_hop(target, ttl, timeout_duration).await;
+        let hop = perform_hop(target, ttl, timeout_duration, source).await;
 
         if hop.ip == Some(target) {
             completed = true;
@@ -215,15 +372,20 @@ pub async fn trace_route(
     }
 }
 
-async fn perform_hop(target: IpAddr, _ttl: u8, timeout_duration: Duration) -> Hop {
+async fn perform_hop(
+    target: IpAddr,
+    _ttl: u8,
+    timeout_duration: Duration,
+    source: &Source,
+) -> Hop {
     let start_time = Instant::now();
 
     match target {
         IpAddr::V4(target_ipv4) => {
-            perform_ipv4_hop(target_ipv4, timeout_duration, start_time).await
+            perform_ipv4_hop(target_ipv4, timeout_duration, start_time, source).await
         }
         IpAddr::V6(target_ipv6) => {
-            perform_ipv6_hop(target_ipv6, timeout_duration, start_time).await
+            perform_ipv6_hop(target_ipv6, timeout_duration, start_time, source).await
         }
     }
 }
@@ -232,14 +394,12 @@ async fn perform_ipv4_hop(
     target: Ipv4Addr,
     timeout_duration: Duration,
     start_time: Instant,
+    source: &Source,
 ) -> Hop {
-    let socket = UdpSocket::bind("0.0.0.0:0").await.unwrap();
+    let destination = SocketAddr::new(IpAddr::V4(target), 33434);
+    let socket = UdpSocket::from_std(source.udp_socket(destination).unwrap()).unwrap();
 
-    let timeout_result = timeout(
-        timeout_duration,
-        socket.connect(SocketAddr::new(IpAddr::V4(target), 33434)),
-    )
-    .await;
+    let timeout_result = timeout(timeout_duration, socket.connect(destination)).await;
 
     let latency_us = start_time.elapsed().as_micros() as u64;
 
@@ -260,14 +420,12 @@ async fn perform_ipv6_hop(
     target: Ipv6Addr,
     timeout_duration: Duration,
     start_time: Instant,
+    source: &Source,
 ) -> Hop {
-    let socket = UdpSocket::bind("[::]:0").await.unwrap();
+    let destination = SocketAddr::new(IpAddr::V6(target), 33434);
+    let socket = UdpSocket::from_std(source.udp_socket(destination).unwrap()).unwrap();
 
-    let timeout_result = timeout(
-        timeout_duration,
-        socket.connect(SocketAddr::new(IpAddr::V6(target), 33434)),
-    )
-    .await;
+    let timeout_result = timeout(timeout_duration, socket.connect(destination)).await;
 
     let latency_us = start_time.elapsed().as_micros() as u64;
 
@@ -284,61 +442,188 @@ async fn perform_ipv6_hop(
     }
 }
 
-async fn check_port_fast(ip: IpAddr, port: u16, timeout_duration: Duration) -> bool {
-    let addr = SocketAddr::new(ip, port);
-    let socket = match TcpSocket::new_v4() {
-        Ok(s) => s,
-        Err(_) => return false,
-    };
-
-    socket.set_nodelay(true).ok();
-    let stream = timeout(timeout_duration, socket.connect(addr)).await;
-    stream.is_ok()
+/// What the port scan found open on one host.
+#[derive(Debug, Default)]
+struct OpenPorts {
+    tcp: Vec<u16>,
+    udp: Vec<u16>,
+    banners: HashMap<u16, String>,
+    services: HashMap<u16, ServiceMatch>,
 }
 
-async fn check_ports_batch(ip: IpAddr, ports: &[u16], timeout_duration: Duration) -> Vec<u16> {
-    let mut open_ports = Vec::new();
-    let mut handles = Vec::new();
-    let ip_clone = ip;
-
-    for &port in ports {
-        let handle = tokio::spawn(check_port_fast(ip_clone, port, timeout_duration));
-        handles.push((port, handle));
-    }
-
-    for (port, handle) in handles {
-        if handle.await.unwrap_or(false) {
-            open_ports.push(port);
+/// Scans `targets` as `probe` says and describes every host that has an
+/// open port. `on_progress` gets the fraction of the scan done so far.
+pub async fn probe_hosts(
+    targets: &[IpAddr],
+    probe: &PortProbe,
+    source: &Source,
+    cancel_flag: &AtomicBool,
+    on_progress: Option<&(dyn Fn(f32) + Send + Sync)>,
+) -> HashMap<IpAddr, ProbedHost> {
+    let cancel = CancelToken::new();
+    let batch_size = probe.max_concurrent.clamp(1, u16::MAX as usize) as u16;
+    let total_ports = (probe.tcp_ports.len() + probe.udp_ports.len()).max(1) as f32;
+    let mut done = 0.0;
+    let mut open: HashMap<IpAddr, OpenPorts> = HashMap::new();
+    let mut host_rtts = HashMap::new();
+
+    for (ports, udp) in [(&probe.tcp_ports, false), (&probe.udp_ports, true)] {
+        if ports.is_empty() || cancel_flag.load(Ordering::Relaxed) {
+            continue;
+        }
+        let mut scanner = Scanner::builder(targets)
+            .with_ports(ports.clone())
+            .with_batch_size(batch_size)
+            .with_timeout(probe.timeout)
+            .with_tries(probe.tries)
+            .with_udp(udp)
+            .with_source(source.clone())
+            .with_cancel_token(cancel.clone())
+            .with_banner_grab(BannerGrab {
+                timeout: probe.timeout,
+                ..BannerGrab::default()
+            });
+        if let Some(rate) = probe.max_rate {
+            scanner = scanner.with_max_rate(rate);
+        }
+        let scanner = scanner.build();
+
+        // The flag is only looked at between events, progress comes often
+        // enough for that.
+        let share = ports.len() as f32 / total_ports;
+        let report = scanner
+            .run_with(|event| {
+                if cancel_flag.load(Ordering::Relaxed) {
+                    cancel.cancel();
+                }
+                if let (ScanEvent::Progress(progress), Some(on_progress)) = (&event, on_progress) {
+                    on_progress(done + progress.fraction() * share);
+                }
+            })
+            .await;
+        done += share;
+
+        for result in report.results.into_iter().filter(|result| result.state.is_open()) {
+            let port = result.socket.port();
+            let host = open.entry(result.socket.ip()).or_default();
+            if udp {
+                host.udp.push(port);
+                continue;
+            }
+            host.tcp.push(port);
+            if let Some(banner) = result.banner {
+                if let Some(service) = ServiceProbes::embedded().match_banner(&banner) {
+                    host.services.insert(port, service);
+                }
+                host.banners.insert(port, banner.to_string());
+            }
+        }
+        // The TCP scan goes first, its connects time the host best.
+        for (ip, rtt) in report.host_rtts {
+            host_rtts.entry(ip).or_insert(rtt);
         }
     }
 
-    open_ports
+    futures::stream::iter(open)
+        .map(|(ip, ports)| describe_host(ip, ports, host_rtts.get(&ip).copied(), probe.timeout))
+        .buffer_unordered(probe.max_concurrent.max(1))
+        .map(|host| (host.ip, host))
+        .collect()
+        .await
 }
 
-pub async fn probe_host(
+/// Probes the ports of a single host, `None` when none of them is open.
+pub async fn probe_host(ip: IpAddr, probe: &PortProbe, source: &Source) -> Option<ProbedHost> {
+    probe_hosts(&[ip], probe, source, &AtomicBool::new(false), None)
+        .await
+        .remove(&ip)
+}
+
+async fn describe_host(
     ip: IpAddr,
-    ports: &[u16],
+    mut open: OpenPorts,
+    rtt: Option<Duration>,
     timeout_duration: Duration,
-) -> Option<ProbedHost> {
-    let open_ports = check_ports_batch(ip, ports, timeout_duration).await;
+) -> ProbedHost {
+    open.tcp.sort_unstable();
+    open.udp.sort_unstable();
+    let tls = inspect_tls_ports(ip, &open.tcp, timeout_duration).await;
+    let http = fingerprint_web_ports(ip, &open.tcp, &open.services, timeout_duration).await;
 
-    if open_ports.is_empty() && !ports.is_empty() {
-        return None;
-    }
+    let os_info = detect_os(&open.tcp);
+    let device_type = DeviceType::classify(open.tcp.as_slice(), None, None);
 
-    let os_info = detect_os(&open_ports);
-    let device_type = DeviceType::classify(open_ports.as_slice(), None, None);
-
-    Some(ProbedHost {
+    ProbedHost {
         ip,
-        ports: open_ports,
+        ports: open.tcp,
+        udp_ports: open.udp,
         hostname: None,
         mac: None,
         vendor: None,
         os_info,
         device_type,
         is_gateway: false,
-    })
+        latency_us: rtt.map(|rtt| rtt.as_micros() as u64),
+        banners: open.banners,
+        services: open.services,
+        tls,
+        http,
+    }
+}
+
+async fn fingerprint_web_ports(
+    ip: IpAddr,
+    ports: &[u16],
+    services: &HashMap<u16, ServiceMatch>,
+    timeout_duration: Duration,
+) -> HashMap<u16, HttpFingerprint> {
+    let prober = HttpProber::new().with_timeout(timeout_duration * 3);
+    let fingerprints = ports
+        .iter()
+        .filter_map(|&port| {
+            // A banner that already answered like HTTP beats the port list
+            let scheme = match services.get(&port) {
+                Some(service) if service.service == "http" => Some(Scheme::Http),
+                _ => web_scheme(port),
+            };
+            scheme.map(|scheme| (port, scheme))
+        })
+        .map(|(port, scheme)| {
+            let prober = &prober;
+            async move {
+                let fingerprint = prober
+                    .fingerprint(SocketAddr::new(ip, port), scheme)
+                    .await
+                    .ok()?;
+                Some((port, fingerprint))
+            }
+        });
+    futures::future::join_all(fingerprints)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+async fn inspect_tls_ports(
+    ip: IpAddr,
+    ports: &[u16],
+    timeout_duration: Duration,
+) -> HashMap<u16, TlsInfo> {
+    // The connect is followed by at least two more round trips.
+    let inspector = TlsInspector::new().with_timeout(timeout_duration * 3);
+    let handshakes = ports.iter().filter(|&&port| is_tls_port(port)).map(|&port| {
+        let inspector = &inspector;
+        async move {
+            let info = inspector.inspect(SocketAddr::new(ip, port), None).await.ok()?;
+            Some((port, info))
+        }
+    });
+    futures::future::join_all(handshakes)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
 }
 
 fn detect_os(ports: &[u16]) -> Option<OSInfo> {
@@ -363,65 +648,69 @@ fn detect_os(ports: &[u16]) -> Option<OSInfo> {
 
 pub async fn discover_network_fast(
     subnet: &str,
+    interface: Option<&str>,
     max_concurrent: usize,
     timeout_ms: u64,
     cancel_flag: Arc<AtomicBool>,
     on_progress: Option<Arc<dyn Fn(f32) + Send + Sync>>,
 ) -> DiscoveryResult {
-    let arp_entries = get_arp_entries().await;
-    let mut probed_hosts = HashMap::new();
+    let probe = PortProbe {
+        timeout: Duration::from_millis(timeout_ms),
+        max_concurrent,
+        ..PortProbe::default()
+    };
+    discover_network_with(subnet, interface, &probe, cancel_flag, on_progress).await
+}
+
+/// Discovers `subnet` like `discover_network_fast`, scanning the ports of
+/// its hosts as `probe` says.
+pub async fn discover_network_with(
+    subnet: &str,
+    interface: Option<&str>,
+    probe: &PortProbe,
+    cancel_flag: Arc<AtomicBool>,
+    on_progress: Option<Arc<dyn Fn(f32) + Send + Sync>>,
+) -> DiscoveryResult {
     let mut traceroutes = Vec::new();
 
-    let targets: Vec<IpAddr> = parse_subnet(subnet).into_iter().take(256).collect();
+    let targets: Vec<IpAddr> = parse_subnet(subnet);
 
-    let total_targets = targets.len();
-    let common_ports: Vec<u16> = COMMON_PORTS.to_vec();
-    let common_ports = Arc::new(common_ports);
-    let timeout = Duration::from_millis(timeout_ms);
-    let traceroute_timeout = Duration::from_millis(timeout_ms / 2);
+    // Sweep first, the cache read afterwards then also holds what the
+    // sweep taught the OS.
+    let local_targets: Vec<Ipv4Addr> = targets
+        .iter()
+        .filter_map(|ip| match ip {
+            IpAddr::V4(ip) => Some(*ip),
+            IpAddr::V6(_) => None,
+        })
+        .collect();
+    let swept = sweep_arp(&local_targets, interface, probe.timeout)
+        .await
+        .unwrap_or_else(|e| {
+            log::debug!("ARP sweep failed, using the ARP cache only: {}", e);
+            Vec::new()
+        });
+    let arp_entries = merge_arp_entries(get_arp_entries().await, swept);
+
+    // Probes leave from the interface that was swept, when there is one.
+    let source = match interface {
+        Some(name) => Source::default().with_interface(name).unwrap_or_else(|e| {
+            log::debug!("Probing from the default route: {}", e);
+            Source::default()
+        }),
+        None => Source::default(),
+    };
+
+    let timeout = probe.timeout;
+    let traceroute_timeout = timeout / 2;
 
-    let semaphore = Arc::new(tokio::sync::Semaphore::new(max_concurrent));
-    let mut handles = Vec::new();
-    
     // Spawn MDNS discovery in the background
     let mdns_handle = tokio::task::spawn_blocking(move || {
-        crate::topology::mdns::resolve_mdns_hostnames(Duration::from_millis(timeout_ms))
+        crate::topology::mdns::resolve_mdns_hostnames(timeout)
     });
 
-    for target in targets {
-        if cancel_flag.load(Ordering::Relaxed) {
-            break;
-        }
-
-        let permit = semaphore.clone().acquire_owned().await.unwrap();
-        let cancel_flag = cancel_flag.clone();
-        let common_ports = common_ports.clone();
-
-        let handle = tokio::spawn(async move {
-            let _permit = permit;
-            if cancel_flag.load(Ordering::Relaxed) {
-                return None;
-            }
-
-            probe_host(target, &common_ports, timeout).await
-        });
-
-        handles.push(handle);
-    }
-
-    let mut completed_targets = 0;
-    for handle in handles {
-        if let Ok(Some(host)) = handle.await {
-            probed_hosts.insert(host.ip, host);
-        }
-
-        completed_targets += 1;
-        if let Some(ref cb) = on_progress {
-            if total_targets > 0 {
-                cb(completed_targets as f32 / total_targets as f32);
-            }
-        }
-    }
+    let mut probed_hosts =
+        probe_hosts(&targets, probe, &source, &cancel_flag, on_progress.as_deref()).await;
 
     // Merge MDNS results
     if let Ok(mdns_map) = mdns_handle.await {
@@ -437,12 +726,18 @@ pub async fn discover_network_fast(
                 let host = ProbedHost {
                     ip,
                     ports: Vec::new(), // We didn't scan it yet, but it exists
+                    udp_ports: Vec::new(),
                     hostname: Some(hostname),
                     mac: None, // MDNS doesn't give MAC directly usually
                     vendor: None,
                     os_info: None,
                     device_type: crate::topology::DeviceType::Unknown,
                     is_gateway: false,
+                    latency_us: None,
+                    banners: HashMap::new(),
+                    services: HashMap::new(),
+                    tls: HashMap::new(),
+                    http: HashMap::new(),
                 };
                 probed_hosts.insert(ip, host);
             }
@@ -451,16 +746,18 @@ pub async fn discover_network_fast(
 
     let hosts_with_ports: Vec<IpAddr> = probed_hosts
         .iter()
-        .filter(|(_, h)| !h.ports.is_empty())
+        .filter(|(_, h)| !h.ports.is_empty() || !h.udp_ports.is_empty())
         .map(|(ip, _)| *ip)
         .collect();
 
     let mut traceroute_handles = Vec::new();
-    let traceroute_semaphore = Arc::new(tokio::sync::Semaphore::new(max_concurrent / 2));
+    let traceroute_semaphore =
+        Arc::new(tokio::sync::Semaphore::new((probe.max_concurrent / 2).max(1)));
 
     for ip in hosts_with_ports {
         let permit = traceroute_semaphore.clone().acquire_owned().await.unwrap();
         let cancel_flag = cancel_flag.clone();
+        let source = source.clone();
 
         let handle = tokio::spawn(async move {
             let _permit = permit;
@@ -468,7 +765,7 @@ pub async fn discover_network_fast(
                 return None;
             }
 
-            Some(trace_route(ip, 30, traceroute_timeout, cancel_flag).await)
+            Some(trace_route(ip, 30, traceroute_timeout, cancel_flag, &source).await)
         });
 
         traceroute_handles.push(handle);
@@ -496,6 +793,7 @@ pub async fn discover_network(
 ) -> DiscoveryResult {
     discover_network_fast(
         _subnet,
+        None,
         max_concurrent,
         timeout.as_millis() as u64,
         cancel_flag,
@@ -504,28 +802,38 @@ pub async fn discover_network(
     .await
 }
 
+/// The addresses of `subnet`, a CIDR block or a single address of either
+/// family, from the start of the block on and `MAX_TARGETS` at most. Host
+/// bits set in the address are ignored, like any router would.
 fn parse_subnet(subnet: &str) -> Vec<IpAddr> {
-    let mut targets = Vec::new();
-
-    if let Some((ip_str, prefix_str)) = subnet.split_once('/') {
-        if let Ok(prefix) = prefix_str.parse::<u8>() {
-            if let Ok(base_ip) = ip_str.parse::<Ipv4Addr>() {
-                let num_hosts = 2_u32.pow((32 - prefix) as u32);
-
-                for i in 0..num_hosts.min(256) {
-                    let octets = base_ip.octets();
-                    let last_octet = octets[3] as u32 + i;
-                    if last_octet <= 255 {
-                        let new_ip =
-                            Ipv4Addr::new(octets[0], octets[1], octets[2], last_octet as u8);
-                        targets.push(IpAddr::V4(new_ip));
-                    }
-                }
-            }
-        }
-    }
-
-    targets
+    let (address, prefix) = subnet.trim().split_once('/').unwrap_or((subnet.trim(), ""));
+    let Ok(base) = address.parse::<IpAddr>() else {
+        return Vec::new();
+    };
+    let bits = if base.is_ipv4() { 32 } else { 128 };
+    let prefix = match prefix {
+        "" => bits,
+        prefix => match prefix.parse::<u32>() {
+            Ok(prefix) if prefix <= bits => prefix,
+            _ => return Vec::new(),
+        },
+    };
+    // A whole /0 of IPv6 does not fit, its block size wraps to 0.
+    let size = 1_u128.checked_shl(bits - prefix).unwrap_or(0);
+    let address = match base {
+        IpAddr::V4(ip) => u128::from(u32::from(ip)),
+        IpAddr::V6(ip) => u128::from(ip),
+    };
+    let first = address & !size.wrapping_sub(1);
+    let count = size.wrapping_sub(1).min(MAX_TARGETS - 1) + 1;
+
+    (0..count)
+        .map(|offset| first + offset)
+        .map_while(|address| match base {
+            IpAddr::V4(_) => u32::try_from(address).ok().map(|ip| IpAddr::V4(ip.into())),
+            IpAddr::V6(_) => Some(IpAddr::V6(address.into())),
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -540,6 +848,106 @@ mod tests {
         assert_eq!(ips[7], IpAddr::V4(Ipv4Addr::new(192, 168, 1, 7)));
     }
 
+    #[test]
+    fn test_parse_subnet_ipv6_and_single_addresses() {
+        let ips = parse_subnet("fd00::ff/126");
+        assert_eq!(ips.len(), 4);
+        assert_eq!(ips[0], "fd00::fc".parse::<IpAddr>().unwrap());
+        assert_eq!(ips[3], "fd00::ff".parse::<IpAddr>().unwrap());
+        assert_eq!(parse_subnet("2001:db8::/64").len(), 256);
+        assert_eq!(parse_subnet("10.0.0.9"), ["10.0.0.9".parse::<IpAddr>().unwrap()]);
+        assert!(parse_subnet("10.0.0.0/33").is_empty());
+    }
+
+    #[test]
+    fn test_parse_subnet_stays_in_the_block() {
+        let ips = parse_subnet("192.168.1.77/24");
+        let block: Vec<IpAddr> = (0..=255)
+            .map(|host| IpAddr::V4(Ipv4Addr::new(192, 168, 1, host)))
+            .collect();
+        assert_eq!(ips, block);
+
+        let ips = parse_subnet("255.255.255.255/24");
+        assert_eq!(ips.len(), 256);
+        assert_eq!(ips[255], IpAddr::V4(Ipv4Addr::BROADCAST));
+
+        assert_eq!(
+            parse_subnet("10.0.0.9/31"),
+            [
+                IpAddr::V4(Ipv4Addr::new(10, 0, 0, 8)),
+                IpAddr::V4(Ipv4Addr::new(10, 0, 0, 9))
+            ]
+        );
+        assert_eq!(parse_subnet("10.0.0.9/32"), [IpAddr::V4(Ipv4Addr::new(10, 0, 0, 9))]);
+    }
+
+    #[test]
+    fn test_probe_host_over_ipv6_from_tokio() {
+        let listener = std::net::TcpListener::bind("[::1]:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let probe = PortProbe {
+            tcp_ports: vec![port],
+            udp_ports: Vec::new(),
+            timeout: Duration::from_millis(300),
+            ..PortProbe::default()
+        };
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        let ip = IpAddr::V6(Ipv6Addr::LOCALHOST);
+        let host = runtime
+            .block_on(probe_host(ip, &probe, &Source::default()))
+            .unwrap();
+        assert_eq!(host.ports, [port]);
+        assert!(host.latency_us.is_some());
+    }
+
+    #[test]
+    fn test_parse_proc_net_arp() {
+        let content = "\
+IP address       HW type     Flags       HW address            Mask     Device
+192.168.1.1      0x1         0x2         aa:bb:cc:dd:ee:01     *        eth0
+192.168.1.7      0x1         0x0         00:00:00:00:00:00     *        eth0
+10.0.0.5         0x1         0x6         aa:bb:cc:dd:ee:02     *        wlan0
+";
+        let entries = parse_proc_net_arp(content);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].mac, "AA:BB:CC:DD:EE:01");
+        assert_eq!(entries[0].iface, "eth0");
+        assert_eq!(entries[0].entry_type, ArpEntryType::Dynamic);
+        assert_eq!(entries[1].iface, "wlan0");
+        assert_eq!(entries[1].entry_type, ArpEntryType::Static);
+    }
+
+    #[test]
+    fn test_swept_entries_replace_cached_ones() {
+        let entry = |last: u8, entry_type: ArpEntryType| ArpEntry {
+            ip: Ipv4Addr::new(192, 168, 1, last),
+            mac: format!("AA:BB:CC:DD:EE:{:02X}", last),
+            iface: "eth0".to_string(),
+            entry_type,
+            response_time_us: None,
+        };
+        let merged = merge_arp_entries(
+            vec![entry(9, ArpEntryType::Dynamic), entry(1, ArpEntryType::Static)],
+            vec![entry(9, ArpEntryType::Swept), entry(4, ArpEntryType::Swept)],
+        );
+        let order: Vec<(u8, ArpEntryType)> = merged
+            .iter()
+            .map(|entry| (entry.ip.octets()[3], entry.entry_type))
+            .collect();
+        assert_eq!(
+            order,
+            [
+                (1, ArpEntryType::Static),
+                (4, ArpEntryType::Swept),
+                (9, ArpEntryType::Swept)
+            ]
+        );
+    }
+
     #[test]
     fn test_common_ports_count() {
         assert_eq!(COMMON_PORTS.len(), 20);
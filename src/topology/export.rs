@@ -1,4 +1,6 @@
+use crate::service::{HttpFingerprint, TlsInfo, DEFAULT_EXPIRY_WARNING_DAYS};
 use crate::topology::{ConnectionType, DeviceType, EdgeData, NodeData, TopologyGraph};
+use chrono::Utc;
 use glam::Vec2;
 use petgraph::graph::NodeIndex;
 use serde::{Deserialize, Serialize};
@@ -50,7 +52,12 @@ impl Exporter {
                 None => continue,
             };
             let node_id = node_data.ip.to_string();
-            let label = Self::format_node_label(node_data, options);
+            let mut label = Self::format_node_label(node_data, options);
+            if options.include_ports {
+                for line in Self::format_tls_lines(node_data) {
+                    label = format!("{}\n{}", label, line);
+                }
+            }
 
             let fillcolor = Self::get_device_color(node_data.device_type);
             let risk_color = if options.include_risk {
@@ -59,7 +66,16 @@ impl Exporter {
                 None
             };
 
-            let color = risk_color.unwrap_or_else(|| fillcolor.clone());
+            // Certificates about to expire outline the node like a high risk would
+            let expiry_color = if Self::has_expiring_certificate(node_data) {
+                Some("#D32F2F".to_string())
+            } else {
+                None
+            };
+
+            let color = expiry_color
+                .or(risk_color)
+                .unwrap_or_else(|| fillcolor.clone());
 
             output.push_str(&format!("  \"{}\" [\n", node_id));
             output.push_str(&format!("    label=\"{}\",\n", label));
@@ -154,6 +170,8 @@ impl Exporter {
             port: u16,
             protocol: String,
             service: Option<String>,
+            tls: Option<TlsInfo>,
+            http: Option<HttpFingerprint>,
         }
 
         #[derive(Serialize)]
@@ -207,6 +225,8 @@ impl Exporter {
                                 port: p.port,
                                 protocol: format!("{:?}", p.protocol),
                                 service: p.service.clone(),
+                                tls: p.tls.clone(),
+                                http: p.http.clone(),
                             })
                             .collect()
                     } else {
@@ -402,6 +422,37 @@ impl Exporter {
         label
     }
 
+    fn format_tls_lines(node_data: &NodeData) -> Vec<String> {
+        let now = Utc::now();
+        node_data
+            .ports
+            .iter()
+            .filter_map(|p| {
+                let tls = p.tls.as_ref()?;
+                let mut line = format!("{}/tls {}", p.port, tls.version);
+                if let Some(certificate) = tls
+                    .expiring(now, DEFAULT_EXPIRY_WARNING_DAYS)
+                    .into_iter()
+                    .min_by_key(|c| c.not_after)
+                {
+                    line = format!("{}, cert {}", line, certificate.expiry_note(now));
+                } else if let Some(leaf) = tls.leaf() {
+                    line = format!("{}, cert until {}", line, leaf.not_after.format("%Y-%m-%d"));
+                }
+                Some(line)
+            })
+            .collect()
+    }
+
+    fn has_expiring_certificate(node_data: &NodeData) -> bool {
+        let now = Utc::now();
+        node_data.ports.iter().any(|p| {
+            p.tls
+                .as_ref()
+                .is_some_and(|tls| !tls.expiring(now, DEFAULT_EXPIRY_WARNING_DAYS).is_empty())
+        })
+    }
+
     fn format_edge_label(edge_data: &EdgeData) -> String {
         let mut parts = Vec::new();
 
@@ -496,10 +547,12 @@ impl Exporter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::service::{CertificateInfo, SniBehaviour};
     use crate::topology::ConnectionType;
     use crate::topology::DeviceType;
     use crate::topology::EdgeData;
     use crate::topology::NodeData;
+    use crate::topology::{PortInfo, PortProtocol};
     use crate::topology::TopologyGraph;
     use glam::Vec2;
     use petgraph::Graph;
@@ -620,6 +673,59 @@ mod tests {
         assert!(parsed.get("edges").is_some());
     }
 
+    fn add_tls_port(graph: &mut TopologyGraph, not_after: chrono::DateTime<chrono::Utc>) {
+        let certificate = CertificateInfo {
+            subject: "CN=nvr.local".to_string(),
+            issuer: "CN=nvr.local".to_string(),
+            sans: vec!["nvr.local".to_string()],
+            serial: "01".to_string(),
+            not_before: not_after - chrono::Duration::days(365),
+            not_after,
+            key_type: "RSA 2048".to_string(),
+            self_signed: true,
+        };
+        let node = graph.graph.node_indices().nth(1).unwrap();
+        graph.graph[node].ports.push(PortInfo {
+            port: 443,
+            protocol: PortProtocol::TCP,
+            service: Some("https".to_string()),
+            version: None,
+            banner: None,
+            tls: Some(TlsInfo {
+                version: "TLSv1.2".to_string(),
+                cipher_suite: "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256".to_string(),
+                alpn: None,
+                sni: SniBehaviour::NotTested,
+                certificates: vec![certificate],
+            }),
+            http: None,
+        });
+    }
+
+    #[test]
+    fn test_export_dot_flags_expired_certificates() {
+        let (mut graph, positions) = create_test_graph();
+        add_tls_port(&mut graph, chrono::Utc::now() - chrono::Duration::days(3));
+
+        let dot = Exporter::export_dot(&graph, &positions, &ExportOptions::default());
+
+        assert!(dot.contains("443/tls TLSv1.2, cert expired"));
+        assert!(dot.contains("color=\"#D32F2F\""));
+    }
+
+    #[test]
+    fn test_export_json_includes_tls() {
+        let (mut graph, positions) = create_test_graph();
+        add_tls_port(&mut graph, chrono::Utc::now() + chrono::Duration::days(200));
+
+        let json = Exporter::export_json(&graph, &positions, &ExportOptions::default()).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("Valid JSON");
+        let tls = &parsed["nodes"][1]["ports"][0]["tls"];
+        assert_eq!(tls["version"], "TLSv1.2");
+        assert_eq!(tls["certificates"][0]["subject"], "CN=nvr.local");
+    }
+
     #[test]
     fn test_export_svg_basic() {
         let (graph, positions) = create_test_graph();
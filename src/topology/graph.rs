@@ -1,6 +1,7 @@
+use crate::service::HttpFingerprint;
 use crate::topology::device::DeviceClassification;
 use crate::topology::discovery;
-use crate::topology::discovery::DiscoveryResult;
+use crate::topology::discovery::{ArpEntryType, DiscoveryResult};
 use crate::topology::geo::geo_lookup;
 use crate::topology::{ConnectionType, DeviceType, EdgeData, NodeData, PortInfo, TopologyStats};
 use petgraph::graph::NodeIndex;
@@ -121,29 +122,37 @@ impl TopologyGraphBuilder {
         let internet_node_idx = self.add_node(internet_ip, internet_data);
 
         for arp in &result.arp_entries {
-            if let Some(mac) = self.lookup_vendor(&arp.mac, &mut mac_cache) {
-                let mut device_type = DeviceType::Unknown;
-                let octet = arp.ip.octets()[3];
-                if octet == 1 || octet == 254 {
-                    device_type = DeviceType::Router;
-                }
-
-                let node_data = NodeData {
-                    ip: IpAddr::V4(arp.ip),
-                    mac: Some(arp.mac.clone()),
-                    hostname: None,
-                    vendor: Some(mac),
-                    device_type,
-                    os_fingerprint: None,
-                    ports: Vec::new(),
-                    risk_score: 10,
-                    geo_location: None,
-                    traceroute_hops: Vec::new(),
-                    first_seen: SystemTime::UNIX_EPOCH,
-                    last_seen: SystemTime::UNIX_EPOCH,
-                };
-                self.add_node(IpAddr::V4(arp.ip), node_data);
+            // Probed hosts get their own, richer node below
+            if result.probed_hosts.contains_key(&IpAddr::V4(arp.ip)) {
+                continue;
+            }
+            let vendor = self.lookup_vendor(&arp.mac, &mut mac_cache);
+            // Cached entries of unknown vendors are often stale, but a host
+            // that just answered our sweep is there for sure
+            if vendor.is_none() && arp.entry_type != ArpEntryType::Swept {
+                continue;
+            }
+            let mut device_type = DeviceType::Unknown;
+            let octet = arp.ip.octets()[3];
+            if octet == 1 || octet == 254 {
+                device_type = DeviceType::Router;
             }
+
+            let node_data = NodeData {
+                ip: IpAddr::V4(arp.ip),
+                mac: Some(arp.mac.clone()),
+                hostname: None,
+                vendor,
+                device_type,
+                os_fingerprint: None,
+                ports: Vec::new(),
+                risk_score: 10,
+                geo_location: None,
+                traceroute_hops: Vec::new(),
+                first_seen: SystemTime::UNIX_EPOCH,
+                last_seen: SystemTime::UNIX_EPOCH,
+            };
+            self.add_node(IpAddr::V4(arp.ip), node_data);
         }
 
         for (ip, host) in &result.probed_hosts {
@@ -171,13 +180,18 @@ impl TopologyGraphBuilder {
             let hostname = host.hostname.clone();
             let os_family = host.os_info.as_ref().map(|info| info.os_family.as_str());
 
-            let (mut device_type, _confidence) = device_classifier.classify(
-                &host.ports,
-                hostname.as_deref(),
-                os_family,
-                host.is_gateway,
-                is_private_ip(ip),
-            );
+            let web_interfaces: Vec<&HttpFingerprint> = host.http.values().collect();
+            let (mut device_type, _confidence) = device_classifier
+                .classify_http(&web_interfaces)
+                .unwrap_or_else(|| {
+                    device_classifier.classify(
+                        &host.ports,
+                        hostname.as_deref(),
+                        os_family,
+                        host.is_gateway,
+                        is_private_ip(ip),
+                    )
+                });
 
             // FORCE Router classification for likely gateways (IP convention or explicit flag)
             let is_actual_gateway = host.is_gateway || {
@@ -203,13 +217,27 @@ impl TopologyGraphBuilder {
             let ports: Vec<PortInfo> = host
                 .ports
                 .iter()
-                .map(|&p| PortInfo {
+                .map(|&p| {
+                    let service = host.services.get(&p);
+                    PortInfo {
+                        port: p,
+                        protocol: crate::topology::PortProtocol::TCP,
+                        service: service.map(|s| s.service.clone()),
+                        version: service.and_then(|s| s.version_string()),
+                        banner: host.banners.get(&p).cloned(),
+                        tls: host.tls.get(&p).cloned(),
+                        http: host.http.get(&p).cloned(),
+                    }
+                })
+                .chain(host.udp_ports.iter().map(|&p| PortInfo {
                     port: p,
-                    protocol: crate::topology::PortProtocol::TCP,
+                    protocol: crate::topology::PortProtocol::UDP,
                     service: None,
                     version: None,
                     banner: None,
-                })
+                    tls: None,
+                    http: None,
+                }))
                 .collect();
 
             let node_data = NodeData {
@@ -298,12 +326,19 @@ impl TopologyGraphBuilder {
             // Actually check if it has NO edges.
             if self.graph.graph.edges(idx).count() == 0 {
                 // It's an orphan host, connect it to the hub
+                let latency_ms = self
+                    .graph
+                    .graph
+                    .node_weight(idx)
+                    .and_then(|node| result.probed_hosts.get(&node.ip))
+                    .and_then(|host| host.latency_us)
+                    .map(|us| (us / 1000) as u32);
                 self.graph.add_edge(
                     hub_idx,
                     idx,
                     EdgeData {
                         connection_type: ConnectionType::Inferred,
-                        latency_ms: None,
+                        latency_ms,
                         hop_count: Some(1),
                         bandwidth_estimate: None,
                     },
@@ -487,6 +522,7 @@ fn calculate_risk_score(ports: &[u16]) -> u8 {
 
 pub async fn discover_and_build_fast(
     subnet: &str,
+    interface: Option<&str>,
     max_concurrent: usize,
     timeout_ms: u64,
     cancel_flag: Arc<AtomicBool>,
@@ -494,6 +530,7 @@ pub async fn discover_and_build_fast(
 ) -> TopologyGraph {
     let result = discovery::discover_network_fast(
         subnet,
+        interface,
         max_concurrent,
         timeout_ms,
         cancel_flag.clone(),
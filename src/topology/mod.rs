@@ -6,6 +6,8 @@ use petgraph::visit::EdgeRef;
 
 use serde::{Deserialize, Serialize};
 
+use crate::service::{HttpFingerprint, TlsInfo};
+
 pub mod device;
 pub mod discovery;
 pub mod export;
@@ -74,6 +76,12 @@ pub struct PortInfo {
     pub service: Option<String>,
     pub version: Option<String>,
     pub banner: Option<String>,
+    /// Handshake and certificate chain, for ports that speak TLS.
+    #[serde(default)]
+    pub tls: Option<TlsInfo>,
+    /// Fingerprint of the web interface, for ports that serve one.
+    #[serde(default)]
+    pub http: Option<HttpFingerprint>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -251,6 +259,8 @@ mod tests {
                 service: Some("https".to_string()),
                 version: None,
                 banner: None,
+                tls: None,
+                http: None,
             }],
             risk_score: 25,
             geo_location: None,
@@ -269,11 +269,14 @@ impl TopologyWidget {
                     action = Some(TopologyAction::ScanHost(node.ip));
                 }
                 
-                let has_web = node.ports.iter().any(|p| p.port == 80 || p.port == 443 || p.port == 8080);
-                if has_web {
+                // Prefer a port that actually answered like a web interface
+                let web_port = node.ports.iter().find(|p| p.http.is_some())
+                    .or_else(|| node.ports.iter().find(|p| p.port == 80 || p.port == 443 || p.port == 8080))
+                    .map(|p| p.port);
+                if let Some(web_port) = web_port {
                     ui.add_space(8.0);
                     if draw_polished_button(ui, RichText::new("🌐 Open Web Interface").size(14.0), Color32::from_rgb(50, 150, 80)).clicked() {
-                        action = Some(TopologyAction::OpenWeb(node.ip, 80));
+                        action = Some(TopologyAction::OpenWeb(node.ip, web_port));
                     }
                 }
 
@@ -294,6 +297,10 @@ impl TopologyWidget {
                                         ui.add_space(8.0);
                                         ui.label(RichText::new(service).size(14.0));
                                     }
+                                    if let Some(title) = port.http.as_ref().and_then(|http| http.title.as_ref()) {
+                                        ui.add_space(8.0);
+                                        ui.label(RichText::new(title).weak().size(12.0));
+                                    }
                                 });
                             }
                         });